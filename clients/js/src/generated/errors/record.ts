@@ -16,14 +16,142 @@ import { RECORD_PROGRAM_ADDRESS } from '../programs';
 
 export const RECORD_ERROR__INCORRECT_AUTHORITY = 0x0; // 0
 export const RECORD_ERROR__OVERFLOW = 0x1; // 1
+export const RECORD_ERROR__EXPECTED_DATA_MISMATCH = 0x2; // 2
+export const RECORD_ERROR__HASH_MISMATCH = 0x3; // 3
+export const RECORD_ERROR__APPEND_ONLY_VIOLATION = 0x4; // 4
+export const RECORD_ERROR__INVALID_RECORD_MODE = 0x5; // 5
+export const RECORD_ERROR__MERKLE_TREE_FULL = 0x6; // 6
+export const RECORD_ERROR__INVALID_MERKLE_PROOF = 0x7; // 7
+export const RECORD_ERROR__SHARD_MISMATCH = 0x8; // 8
+export const RECORD_ERROR__INVALID_NAME = 0x9; // 9
+export const RECORD_ERROR__KEY_NOT_FOUND = 0xa; // 10
+export const RECORD_ERROR__EXTENSION_NOT_FOUND = 0xb; // 11
+export const RECORD_ERROR__INCORRECT_OWNER = 0xc; // 12
+export const RECORD_ERROR__ACCOUNT_NOT_WRITABLE = 0xd; // 13
+export const RECORD_ERROR__DUPLICATE_ACCOUNT = 0xe; // 14
+export const RECORD_ERROR__INVALID_AUTHORITY = 0xf; // 15
+export const RECORD_ERROR__UNINITIALIZED_RECORD = 0x10; // 16
+export const RECORD_ERROR__ALREADY_INITIALIZED = 0x11; // 17
+export const RECORD_ERROR__HEADER_TOO_SMALL = 0x12; // 18
+export const RECORD_ERROR__WRITE_OUT_OF_BOUNDS = 0x13; // 19
+export const RECORD_ERROR__RECORD_FROZEN = 0x14; // 20
+export const RECORD_ERROR__INCORRECT_BENEFICIARY = 0x15; // 21
+export const RECORD_ERROR__RECORD_NOT_EXPIRED = 0x16; // 22
+export const RECORD_ERROR__WRITE_OUTSIDE_WINDOW = 0x17; // 23
+export const RECORD_ERROR__WRITE_RATE_LIMITED = 0x18; // 24
+export const RECORD_ERROR__SESSION_KEY_EXPIRED = 0x19; // 25
+export const RECORD_ERROR__SESSION_BUDGET_EXCEEDED = 0x1a; // 26
+export const RECORD_ERROR__SESSION_KEYS_FULL = 0x1b; // 27
+export const RECORD_ERROR__TOKEN_NOT_HELD = 0x1c; // 28
+export const RECORD_ERROR__INVALID_WRITE_SIGNATURE = 0x1d; // 29
+export const RECORD_ERROR__NONCE_MISMATCH = 0x1e; // 30
+export const RECORD_ERROR__ALREADY_MIGRATED = 0x1f; // 31
+export const RECORD_ERROR__SHARD_INDEX_OUT_OF_RANGE = 0x20; // 32
+export const RECORD_ERROR__SHRINK_BELOW_ENTRIES = 0x21; // 33
+export const RECORD_ERROR__SESSION_KEY_NOT_FOUND = 0x22; // 34
+export const RECORD_ERROR__INVALID_WRITE_WINDOW = 0x23; // 35
+export const RECORD_ERROR__ENTRY_SIZE_MISMATCH = 0x24; // 36
+export const RECORD_ERROR__INVALID_ENTRY_SIZE = 0x25; // 37
+export const RECORD_ERROR__RING_BUFFER_TOO_SMALL = 0x26; // 38
+export const RECORD_ERROR__MERKLE_ACCUMULATOR_TOO_SMALL = 0x27; // 39
+export const RECORD_ERROR__MANIFEST_TOO_SMALL = 0x28; // 40
+export const RECORD_ERROR__KEY_VALUE_TOO_SMALL = 0x29; // 41
+export const RECORD_ERROR__MISSING_RENT_BENEFICIARY = 0x2a; // 42
+export const RECORD_ERROR__UNWRITTEN_RECORD = 0x2b; // 43
 
-export type RecordError = typeof RECORD_ERROR__INCORRECT_AUTHORITY | typeof RECORD_ERROR__OVERFLOW;
+export type RecordError =
+    | typeof RECORD_ERROR__ACCOUNT_NOT_WRITABLE
+    | typeof RECORD_ERROR__ALREADY_INITIALIZED
+    | typeof RECORD_ERROR__ALREADY_MIGRATED
+    | typeof RECORD_ERROR__APPEND_ONLY_VIOLATION
+    | typeof RECORD_ERROR__DUPLICATE_ACCOUNT
+    | typeof RECORD_ERROR__ENTRY_SIZE_MISMATCH
+    | typeof RECORD_ERROR__EXPECTED_DATA_MISMATCH
+    | typeof RECORD_ERROR__EXTENSION_NOT_FOUND
+    | typeof RECORD_ERROR__HASH_MISMATCH
+    | typeof RECORD_ERROR__HEADER_TOO_SMALL
+    | typeof RECORD_ERROR__INCORRECT_AUTHORITY
+    | typeof RECORD_ERROR__INCORRECT_BENEFICIARY
+    | typeof RECORD_ERROR__INCORRECT_OWNER
+    | typeof RECORD_ERROR__INVALID_AUTHORITY
+    | typeof RECORD_ERROR__INVALID_ENTRY_SIZE
+    | typeof RECORD_ERROR__INVALID_MERKLE_PROOF
+    | typeof RECORD_ERROR__INVALID_NAME
+    | typeof RECORD_ERROR__INVALID_RECORD_MODE
+    | typeof RECORD_ERROR__INVALID_WRITE_SIGNATURE
+    | typeof RECORD_ERROR__INVALID_WRITE_WINDOW
+    | typeof RECORD_ERROR__KEY_NOT_FOUND
+    | typeof RECORD_ERROR__KEY_VALUE_TOO_SMALL
+    | typeof RECORD_ERROR__MANIFEST_TOO_SMALL
+    | typeof RECORD_ERROR__MERKLE_ACCUMULATOR_TOO_SMALL
+    | typeof RECORD_ERROR__MERKLE_TREE_FULL
+    | typeof RECORD_ERROR__MISSING_RENT_BENEFICIARY
+    | typeof RECORD_ERROR__NONCE_MISMATCH
+    | typeof RECORD_ERROR__OVERFLOW
+    | typeof RECORD_ERROR__RECORD_FROZEN
+    | typeof RECORD_ERROR__RECORD_NOT_EXPIRED
+    | typeof RECORD_ERROR__RING_BUFFER_TOO_SMALL
+    | typeof RECORD_ERROR__SESSION_BUDGET_EXCEEDED
+    | typeof RECORD_ERROR__SESSION_KEY_EXPIRED
+    | typeof RECORD_ERROR__SESSION_KEY_NOT_FOUND
+    | typeof RECORD_ERROR__SESSION_KEYS_FULL
+    | typeof RECORD_ERROR__SHARD_INDEX_OUT_OF_RANGE
+    | typeof RECORD_ERROR__SHARD_MISMATCH
+    | typeof RECORD_ERROR__SHRINK_BELOW_ENTRIES
+    | typeof RECORD_ERROR__TOKEN_NOT_HELD
+    | typeof RECORD_ERROR__UNINITIALIZED_RECORD
+    | typeof RECORD_ERROR__UNWRITTEN_RECORD
+    | typeof RECORD_ERROR__WRITE_OUT_OF_BOUNDS
+    | typeof RECORD_ERROR__WRITE_OUTSIDE_WINDOW
+    | typeof RECORD_ERROR__WRITE_RATE_LIMITED;
 
 let recordErrorMessages: Record<RecordError, string> | undefined;
 if (process.env['NODE_ENV'] !== 'production') {
     recordErrorMessages = {
+        [RECORD_ERROR__ACCOUNT_NOT_WRITABLE]: `Account must be writable`,
+        [RECORD_ERROR__ALREADY_INITIALIZED]: `Record account already initialized`,
+        [RECORD_ERROR__ALREADY_MIGRATED]: `Record already uses the extended header`,
+        [RECORD_ERROR__APPEND_ONLY_VIOLATION]: `Append-only record data cannot be rewritten or shrunk`,
+        [RECORD_ERROR__DUPLICATE_ACCOUNT]: `The same account was provided for more than one role`,
+        [RECORD_ERROR__ENTRY_SIZE_MISMATCH]: `Entry does not match the ring buffer entry size`,
+        [RECORD_ERROR__EXPECTED_DATA_MISMATCH]: `Record data does not match the expected bytes`,
+        [RECORD_ERROR__EXTENSION_NOT_FOUND]: `Extension not found in the record's extension region`,
+        [RECORD_ERROR__HASH_MISMATCH]: `Buffer payload does not match the expected hash`,
+        [RECORD_ERROR__HEADER_TOO_SMALL]: `Record account too small to hold its header`,
         [RECORD_ERROR__INCORRECT_AUTHORITY]: `Incorrect authority provided on update or delete`,
+        [RECORD_ERROR__INCORRECT_BENEFICIARY]: `Lamports must be refunded to the record's rent beneficiary`,
+        [RECORD_ERROR__INCORRECT_OWNER]: `Account is not owned by the record program`,
+        [RECORD_ERROR__INVALID_AUTHORITY]: `Address cannot be used as the record authority`,
+        [RECORD_ERROR__INVALID_ENTRY_SIZE]: `Ring buffer entry size must be non-zero`,
+        [RECORD_ERROR__INVALID_MERKLE_PROOF]: `Merkle inclusion proof does not match the record's root`,
+        [RECORD_ERROR__INVALID_NAME]: `Name must be 1 to 32 bytes of UTF-8`,
+        [RECORD_ERROR__INVALID_RECORD_MODE]: `Instruction not supported by the record's mode`,
+        [RECORD_ERROR__INVALID_WRITE_SIGNATURE]: `Write is not covered by a matching Ed25519 signature`,
+        [RECORD_ERROR__INVALID_WRITE_WINDOW]: `Write window must not end before it starts`,
+        [RECORD_ERROR__KEY_NOT_FOUND]: `Key not found in the key-value record`,
+        [RECORD_ERROR__KEY_VALUE_TOO_SMALL]: `Key-value record too small to hold its entries`,
+        [RECORD_ERROR__MANIFEST_TOO_SMALL]: `Manifest record too small to hold its shard entries`,
+        [RECORD_ERROR__MERKLE_ACCUMULATOR_TOO_SMALL]: `Record account too small to hold the Merkle accumulator`,
+        [RECORD_ERROR__MERKLE_TREE_FULL]: `Merkle accumulator cannot hold any more leaves`,
+        [RECORD_ERROR__MISSING_RENT_BENEFICIARY]: `Token-gated records need a rent beneficiary to be closed on expiry`,
+        [RECORD_ERROR__NONCE_MISMATCH]: `Signed write nonce does not match the record's nonce`,
         [RECORD_ERROR__OVERFLOW]: `Calculation overflow`,
+        [RECORD_ERROR__RECORD_FROZEN]: `Record content is finalized and cannot be modified`,
+        [RECORD_ERROR__RECORD_NOT_EXPIRED]: `Record has not expired yet`,
+        [RECORD_ERROR__RING_BUFFER_TOO_SMALL]: `Record account too small to hold a ring buffer entry`,
+        [RECORD_ERROR__SESSION_BUDGET_EXCEEDED]: `Write exceeds the session key's byte budget`,
+        [RECORD_ERROR__SESSION_KEY_EXPIRED]: `Session key has expired`,
+        [RECORD_ERROR__SESSION_KEY_NOT_FOUND]: `Session key is not registered for the record`,
+        [RECORD_ERROR__SESSION_KEYS_FULL]: `Record cannot hold any more session keys`,
+        [RECORD_ERROR__SHARD_INDEX_OUT_OF_RANGE]: `Shard index is past the end of the manifest`,
+        [RECORD_ERROR__SHARD_MISMATCH]: `Shard does not match the manifest entry`,
+        [RECORD_ERROR__SHRINK_BELOW_ENTRIES]: `Record cannot be shrunk below its entries`,
+        [RECORD_ERROR__TOKEN_NOT_HELD]: `Signer does not hold the token gating the record`,
+        [RECORD_ERROR__UNINITIALIZED_RECORD]: `Record account not initialized`,
+        [RECORD_ERROR__UNWRITTEN_RECORD]: `Record account passed without any write`,
+        [RECORD_ERROR__WRITE_OUT_OF_BOUNDS]: `Write or checked range extends past the end of the record`,
+        [RECORD_ERROR__WRITE_OUTSIDE_WINDOW]: `Record cannot be written outside its write window`,
+        [RECORD_ERROR__WRITE_RATE_LIMITED]: `Record was written too recently to be written again`,
     };
 }
 
@@ -6,8 +6,39 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './appendLeaf';
+export * from './appendShard';
 export * from './closeAccount';
+export * from './closeExpired';
+export * from './commit';
+export * from './deleteKey';
+export * from './finalizeContent';
 export * from './initialize';
+export * from './initializeAligned';
+export * from './initializeChecked';
+export * from './initializeContent';
+export * from './initializeMerkleAccumulator';
+export * from './initializeRingBuffer';
+export * from './initializeSponsored';
+export * from './initializeWithMode';
+export * from './migrate';
+export * from './pushEntry';
 export * from './reallocate';
+export * from './reallocateFunded';
+export * from './registerName';
+export * from './registerSessionKey';
+export * from './releaseName';
+export * from './replaceShard';
+export * from './repointName';
+export * from './revokeSessionKey';
 export * from './setAuthority';
+export * from './setAuthorityChecked';
+export * from './setExpiry';
+export * from './setKey';
+export * from './setTokenGatedAuthority';
+export * from './setWritePolicy';
+export * from './verifyLeaf';
 export * from './write';
+export * from './writeIfEqual';
+export * from './writeMultiple';
+export * from './writeSigned';
@@ -34,26 +34,150 @@ import {
 } from '@solana/kit/program-client-core';
 import { getRecordDataCodec, type RecordData, type RecordDataArgs } from '../accounts';
 import {
+    getAppendLeafInstruction,
+    getAppendShardInstruction,
     getCloseAccountInstruction,
+    getCloseExpiredInstruction,
+    getCommitInstruction,
+    getDeleteKeyInstruction,
+    getFinalizeContentInstruction,
+    getInitializeAlignedInstruction,
+    getInitializeCheckedInstruction,
+    getInitializeContentInstruction,
     getInitializeInstruction,
+    getInitializeMerkleAccumulatorInstruction,
+    getInitializeRingBufferInstruction,
+    getInitializeSponsoredInstruction,
+    getInitializeWithModeInstruction,
+    getMigrateInstruction,
+    getPushEntryInstruction,
+    getReallocateFundedInstruction,
     getReallocateInstruction,
+    getRegisterNameInstruction,
+    getRegisterSessionKeyInstruction,
+    getReleaseNameInstruction,
+    getReplaceShardInstruction,
+    getRepointNameInstruction,
+    getRevokeSessionKeyInstruction,
+    getSetAuthorityCheckedInstruction,
     getSetAuthorityInstruction,
+    getSetExpiryInstruction,
+    getSetKeyInstruction,
+    getSetTokenGatedAuthorityInstruction,
+    getSetWritePolicyInstruction,
+    getVerifyLeafInstruction,
+    getWriteIfEqualInstruction,
     getWriteInstruction,
+    getWriteMultipleInstruction,
+    getWriteSignedInstruction,
+    parseAppendLeafInstruction,
+    parseAppendShardInstruction,
     parseCloseAccountInstruction,
+    parseCloseExpiredInstruction,
+    parseCommitInstruction,
+    parseDeleteKeyInstruction,
+    parseFinalizeContentInstruction,
+    parseInitializeAlignedInstruction,
+    parseInitializeCheckedInstruction,
+    parseInitializeContentInstruction,
     parseInitializeInstruction,
+    parseInitializeMerkleAccumulatorInstruction,
+    parseInitializeRingBufferInstruction,
+    parseInitializeSponsoredInstruction,
+    parseInitializeWithModeInstruction,
+    parseMigrateInstruction,
+    parsePushEntryInstruction,
+    parseReallocateFundedInstruction,
     parseReallocateInstruction,
+    parseRegisterNameInstruction,
+    parseRegisterSessionKeyInstruction,
+    parseReleaseNameInstruction,
+    parseReplaceShardInstruction,
+    parseRepointNameInstruction,
+    parseRevokeSessionKeyInstruction,
+    parseSetAuthorityCheckedInstruction,
     parseSetAuthorityInstruction,
+    parseSetExpiryInstruction,
+    parseSetKeyInstruction,
+    parseSetTokenGatedAuthorityInstruction,
+    parseSetWritePolicyInstruction,
+    parseVerifyLeafInstruction,
+    parseWriteIfEqualInstruction,
     parseWriteInstruction,
+    parseWriteMultipleInstruction,
+    parseWriteSignedInstruction,
+    type AppendLeafInput,
+    type AppendShardInput,
     type CloseAccountInput,
+    type CloseExpiredInput,
+    type CommitInput,
+    type DeleteKeyInput,
+    type FinalizeContentInput,
+    type InitializeAlignedInput,
+    type InitializeCheckedInput,
+    type InitializeContentInput,
     type InitializeInput,
+    type InitializeMerkleAccumulatorInput,
+    type InitializeRingBufferInput,
+    type InitializeSponsoredInput,
+    type InitializeWithModeInput,
+    type MigrateInput,
+    type ParsedAppendLeafInstruction,
+    type ParsedAppendShardInstruction,
     type ParsedCloseAccountInstruction,
+    type ParsedCloseExpiredInstruction,
+    type ParsedCommitInstruction,
+    type ParsedDeleteKeyInstruction,
+    type ParsedFinalizeContentInstruction,
+    type ParsedInitializeAlignedInstruction,
+    type ParsedInitializeCheckedInstruction,
+    type ParsedInitializeContentInstruction,
     type ParsedInitializeInstruction,
+    type ParsedInitializeMerkleAccumulatorInstruction,
+    type ParsedInitializeRingBufferInstruction,
+    type ParsedInitializeSponsoredInstruction,
+    type ParsedInitializeWithModeInstruction,
+    type ParsedMigrateInstruction,
+    type ParsedPushEntryInstruction,
+    type ParsedReallocateFundedInstruction,
     type ParsedReallocateInstruction,
+    type ParsedRegisterNameInstruction,
+    type ParsedRegisterSessionKeyInstruction,
+    type ParsedReleaseNameInstruction,
+    type ParsedReplaceShardInstruction,
+    type ParsedRepointNameInstruction,
+    type ParsedRevokeSessionKeyInstruction,
+    type ParsedSetAuthorityCheckedInstruction,
     type ParsedSetAuthorityInstruction,
+    type ParsedSetExpiryInstruction,
+    type ParsedSetKeyInstruction,
+    type ParsedSetTokenGatedAuthorityInstruction,
+    type ParsedSetWritePolicyInstruction,
+    type ParsedVerifyLeafInstruction,
+    type ParsedWriteIfEqualInstruction,
     type ParsedWriteInstruction,
+    type ParsedWriteMultipleInstruction,
+    type ParsedWriteSignedInstruction,
+    type PushEntryInput,
+    type ReallocateFundedInput,
     type ReallocateInput,
+    type RegisterNameInput,
+    type RegisterSessionKeyInput,
+    type ReleaseNameInput,
+    type ReplaceShardInput,
+    type RepointNameInput,
+    type RevokeSessionKeyInput,
+    type SetAuthorityCheckedInput,
     type SetAuthorityInput,
+    type SetExpiryInput,
+    type SetKeyInput,
+    type SetTokenGatedAuthorityInput,
+    type SetWritePolicyInput,
+    type VerifyLeafInput,
+    type WriteIfEqualInput,
     type WriteInput,
+    type WriteMultipleInput,
+    type WriteSignedInput,
 } from '../instructions';
 
 export const RECORD_PROGRAM_ADDRESS =
@@ -80,6 +204,37 @@ export enum RecordInstruction {
     SetAuthority,
     CloseAccount,
     Reallocate,
+    WriteIfEqual,
+    WriteMultiple,
+    Commit,
+    InitializeWithMode,
+    InitializeRingBuffer,
+    PushEntry,
+    InitializeMerkleAccumulator,
+    AppendLeaf,
+    VerifyLeaf,
+    AppendShard,
+    ReplaceShard,
+    InitializeContent,
+    FinalizeContent,
+    RegisterName,
+    RepointName,
+    ReleaseName,
+    SetKey,
+    DeleteKey,
+    Migrate,
+    InitializeAligned,
+    InitializeChecked,
+    SetAuthorityChecked,
+    InitializeSponsored,
+    ReallocateFunded,
+    SetExpiry,
+    CloseExpired,
+    SetWritePolicy,
+    RegisterSessionKey,
+    RevokeSessionKey,
+    SetTokenGatedAuthority,
+    WriteSigned,
 }
 
 export function identifyRecordInstruction(
@@ -101,6 +256,99 @@ export function identifyRecordInstruction(
     if (containsBytes(data, getU8Encoder().encode(4), 0)) {
         return RecordInstruction.Reallocate;
     }
+    if (containsBytes(data, getU8Encoder().encode(5), 0)) {
+        return RecordInstruction.WriteIfEqual;
+    }
+    if (containsBytes(data, getU8Encoder().encode(6), 0)) {
+        return RecordInstruction.WriteMultiple;
+    }
+    if (containsBytes(data, getU8Encoder().encode(7), 0)) {
+        return RecordInstruction.Commit;
+    }
+    if (containsBytes(data, getU8Encoder().encode(8), 0)) {
+        return RecordInstruction.InitializeWithMode;
+    }
+    if (containsBytes(data, getU8Encoder().encode(9), 0)) {
+        return RecordInstruction.InitializeRingBuffer;
+    }
+    if (containsBytes(data, getU8Encoder().encode(10), 0)) {
+        return RecordInstruction.PushEntry;
+    }
+    if (containsBytes(data, getU8Encoder().encode(11), 0)) {
+        return RecordInstruction.InitializeMerkleAccumulator;
+    }
+    if (containsBytes(data, getU8Encoder().encode(12), 0)) {
+        return RecordInstruction.AppendLeaf;
+    }
+    if (containsBytes(data, getU8Encoder().encode(13), 0)) {
+        return RecordInstruction.VerifyLeaf;
+    }
+    if (containsBytes(data, getU8Encoder().encode(14), 0)) {
+        return RecordInstruction.AppendShard;
+    }
+    if (containsBytes(data, getU8Encoder().encode(15), 0)) {
+        return RecordInstruction.ReplaceShard;
+    }
+    if (containsBytes(data, getU8Encoder().encode(16), 0)) {
+        return RecordInstruction.InitializeContent;
+    }
+    if (containsBytes(data, getU8Encoder().encode(17), 0)) {
+        return RecordInstruction.FinalizeContent;
+    }
+    if (containsBytes(data, getU8Encoder().encode(18), 0)) {
+        return RecordInstruction.RegisterName;
+    }
+    if (containsBytes(data, getU8Encoder().encode(19), 0)) {
+        return RecordInstruction.RepointName;
+    }
+    if (containsBytes(data, getU8Encoder().encode(20), 0)) {
+        return RecordInstruction.ReleaseName;
+    }
+    if (containsBytes(data, getU8Encoder().encode(21), 0)) {
+        return RecordInstruction.SetKey;
+    }
+    if (containsBytes(data, getU8Encoder().encode(22), 0)) {
+        return RecordInstruction.DeleteKey;
+    }
+    if (containsBytes(data, getU8Encoder().encode(23), 0)) {
+        return RecordInstruction.Migrate;
+    }
+    if (containsBytes(data, getU8Encoder().encode(24), 0)) {
+        return RecordInstruction.InitializeAligned;
+    }
+    if (containsBytes(data, getU8Encoder().encode(25), 0)) {
+        return RecordInstruction.InitializeChecked;
+    }
+    if (containsBytes(data, getU8Encoder().encode(26), 0)) {
+        return RecordInstruction.SetAuthorityChecked;
+    }
+    if (containsBytes(data, getU8Encoder().encode(27), 0)) {
+        return RecordInstruction.InitializeSponsored;
+    }
+    if (containsBytes(data, getU8Encoder().encode(28), 0)) {
+        return RecordInstruction.ReallocateFunded;
+    }
+    if (containsBytes(data, getU8Encoder().encode(29), 0)) {
+        return RecordInstruction.SetExpiry;
+    }
+    if (containsBytes(data, getU8Encoder().encode(30), 0)) {
+        return RecordInstruction.CloseExpired;
+    }
+    if (containsBytes(data, getU8Encoder().encode(31), 0)) {
+        return RecordInstruction.SetWritePolicy;
+    }
+    if (containsBytes(data, getU8Encoder().encode(32), 0)) {
+        return RecordInstruction.RegisterSessionKey;
+    }
+    if (containsBytes(data, getU8Encoder().encode(33), 0)) {
+        return RecordInstruction.RevokeSessionKey;
+    }
+    if (containsBytes(data, getU8Encoder().encode(34), 0)) {
+        return RecordInstruction.SetTokenGatedAuthority;
+    }
+    if (containsBytes(data, getU8Encoder().encode(35), 0)) {
+        return RecordInstruction.WriteSigned;
+    }
     throw new SolanaError(SOLANA_ERROR__PROGRAM_CLIENTS__FAILED_TO_IDENTIFY_INSTRUCTION, {
         instructionData: data,
         programName: 'record',
@@ -112,7 +360,42 @@ export type ParsedRecordInstruction<TProgram extends string = 'recr1L3PCGKLbckBq
     | ({ instructionType: RecordInstruction.Write } & ParsedWriteInstruction<TProgram>)
     | ({ instructionType: RecordInstruction.SetAuthority } & ParsedSetAuthorityInstruction<TProgram>)
     | ({ instructionType: RecordInstruction.CloseAccount } & ParsedCloseAccountInstruction<TProgram>)
-    | ({ instructionType: RecordInstruction.Reallocate } & ParsedReallocateInstruction<TProgram>);
+    | ({ instructionType: RecordInstruction.Reallocate } & ParsedReallocateInstruction<TProgram>)
+    | ({ instructionType: RecordInstruction.WriteIfEqual } & ParsedWriteIfEqualInstruction<TProgram>)
+    | ({ instructionType: RecordInstruction.WriteMultiple } & ParsedWriteMultipleInstruction<TProgram>)
+    | ({ instructionType: RecordInstruction.Commit } & ParsedCommitInstruction<TProgram>)
+    | ({ instructionType: RecordInstruction.InitializeWithMode } & ParsedInitializeWithModeInstruction<TProgram>)
+    | ({ instructionType: RecordInstruction.InitializeRingBuffer } & ParsedInitializeRingBufferInstruction<TProgram>)
+    | ({ instructionType: RecordInstruction.PushEntry } & ParsedPushEntryInstruction<TProgram>)
+    | ({
+          instructionType: RecordInstruction.InitializeMerkleAccumulator;
+      } & ParsedInitializeMerkleAccumulatorInstruction<TProgram>)
+    | ({ instructionType: RecordInstruction.AppendLeaf } & ParsedAppendLeafInstruction<TProgram>)
+    | ({ instructionType: RecordInstruction.VerifyLeaf } & ParsedVerifyLeafInstruction<TProgram>)
+    | ({ instructionType: RecordInstruction.AppendShard } & ParsedAppendShardInstruction<TProgram>)
+    | ({ instructionType: RecordInstruction.ReplaceShard } & ParsedReplaceShardInstruction<TProgram>)
+    | ({ instructionType: RecordInstruction.InitializeContent } & ParsedInitializeContentInstruction<TProgram>)
+    | ({ instructionType: RecordInstruction.FinalizeContent } & ParsedFinalizeContentInstruction<TProgram>)
+    | ({ instructionType: RecordInstruction.RegisterName } & ParsedRegisterNameInstruction<TProgram>)
+    | ({ instructionType: RecordInstruction.RepointName } & ParsedRepointNameInstruction<TProgram>)
+    | ({ instructionType: RecordInstruction.ReleaseName } & ParsedReleaseNameInstruction<TProgram>)
+    | ({ instructionType: RecordInstruction.SetKey } & ParsedSetKeyInstruction<TProgram>)
+    | ({ instructionType: RecordInstruction.DeleteKey } & ParsedDeleteKeyInstruction<TProgram>)
+    | ({ instructionType: RecordInstruction.Migrate } & ParsedMigrateInstruction<TProgram>)
+    | ({ instructionType: RecordInstruction.InitializeAligned } & ParsedInitializeAlignedInstruction<TProgram>)
+    | ({ instructionType: RecordInstruction.InitializeChecked } & ParsedInitializeCheckedInstruction<TProgram>)
+    | ({ instructionType: RecordInstruction.SetAuthorityChecked } & ParsedSetAuthorityCheckedInstruction<TProgram>)
+    | ({ instructionType: RecordInstruction.InitializeSponsored } & ParsedInitializeSponsoredInstruction<TProgram>)
+    | ({ instructionType: RecordInstruction.ReallocateFunded } & ParsedReallocateFundedInstruction<TProgram>)
+    | ({ instructionType: RecordInstruction.SetExpiry } & ParsedSetExpiryInstruction<TProgram>)
+    | ({ instructionType: RecordInstruction.CloseExpired } & ParsedCloseExpiredInstruction<TProgram>)
+    | ({ instructionType: RecordInstruction.SetWritePolicy } & ParsedSetWritePolicyInstruction<TProgram>)
+    | ({ instructionType: RecordInstruction.RegisterSessionKey } & ParsedRegisterSessionKeyInstruction<TProgram>)
+    | ({ instructionType: RecordInstruction.RevokeSessionKey } & ParsedRevokeSessionKeyInstruction<TProgram>)
+    | ({
+          instructionType: RecordInstruction.SetTokenGatedAuthority;
+      } & ParsedSetTokenGatedAuthorityInstruction<TProgram>)
+    | ({ instructionType: RecordInstruction.WriteSigned } & ParsedWriteSignedInstruction<TProgram>);
 
 export function parseRecordInstruction<TProgram extends string>(
     instruction: Instruction<TProgram> & InstructionWithData<ReadonlyUint8Array>,
@@ -139,6 +422,172 @@ export function parseRecordInstruction<TProgram extends string>(
             assertIsInstructionWithAccounts(instruction);
             return { instructionType: RecordInstruction.Reallocate, ...parseReallocateInstruction(instruction) };
         }
+        case RecordInstruction.WriteIfEqual: {
+            assertIsInstructionWithAccounts(instruction);
+            return { instructionType: RecordInstruction.WriteIfEqual, ...parseWriteIfEqualInstruction(instruction) };
+        }
+        case RecordInstruction.WriteMultiple: {
+            assertIsInstructionWithAccounts(instruction);
+            return { instructionType: RecordInstruction.WriteMultiple, ...parseWriteMultipleInstruction(instruction) };
+        }
+        case RecordInstruction.Commit: {
+            assertIsInstructionWithAccounts(instruction);
+            return { instructionType: RecordInstruction.Commit, ...parseCommitInstruction(instruction) };
+        }
+        case RecordInstruction.InitializeWithMode: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: RecordInstruction.InitializeWithMode,
+                ...parseInitializeWithModeInstruction(instruction),
+            };
+        }
+        case RecordInstruction.InitializeRingBuffer: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: RecordInstruction.InitializeRingBuffer,
+                ...parseInitializeRingBufferInstruction(instruction),
+            };
+        }
+        case RecordInstruction.PushEntry: {
+            assertIsInstructionWithAccounts(instruction);
+            return { instructionType: RecordInstruction.PushEntry, ...parsePushEntryInstruction(instruction) };
+        }
+        case RecordInstruction.InitializeMerkleAccumulator: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: RecordInstruction.InitializeMerkleAccumulator,
+                ...parseInitializeMerkleAccumulatorInstruction(instruction),
+            };
+        }
+        case RecordInstruction.AppendLeaf: {
+            assertIsInstructionWithAccounts(instruction);
+            return { instructionType: RecordInstruction.AppendLeaf, ...parseAppendLeafInstruction(instruction) };
+        }
+        case RecordInstruction.VerifyLeaf: {
+            assertIsInstructionWithAccounts(instruction);
+            return { instructionType: RecordInstruction.VerifyLeaf, ...parseVerifyLeafInstruction(instruction) };
+        }
+        case RecordInstruction.AppendShard: {
+            assertIsInstructionWithAccounts(instruction);
+            return { instructionType: RecordInstruction.AppendShard, ...parseAppendShardInstruction(instruction) };
+        }
+        case RecordInstruction.ReplaceShard: {
+            assertIsInstructionWithAccounts(instruction);
+            return { instructionType: RecordInstruction.ReplaceShard, ...parseReplaceShardInstruction(instruction) };
+        }
+        case RecordInstruction.InitializeContent: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: RecordInstruction.InitializeContent,
+                ...parseInitializeContentInstruction(instruction),
+            };
+        }
+        case RecordInstruction.FinalizeContent: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: RecordInstruction.FinalizeContent,
+                ...parseFinalizeContentInstruction(instruction),
+            };
+        }
+        case RecordInstruction.RegisterName: {
+            assertIsInstructionWithAccounts(instruction);
+            return { instructionType: RecordInstruction.RegisterName, ...parseRegisterNameInstruction(instruction) };
+        }
+        case RecordInstruction.RepointName: {
+            assertIsInstructionWithAccounts(instruction);
+            return { instructionType: RecordInstruction.RepointName, ...parseRepointNameInstruction(instruction) };
+        }
+        case RecordInstruction.ReleaseName: {
+            assertIsInstructionWithAccounts(instruction);
+            return { instructionType: RecordInstruction.ReleaseName, ...parseReleaseNameInstruction(instruction) };
+        }
+        case RecordInstruction.SetKey: {
+            assertIsInstructionWithAccounts(instruction);
+            return { instructionType: RecordInstruction.SetKey, ...parseSetKeyInstruction(instruction) };
+        }
+        case RecordInstruction.DeleteKey: {
+            assertIsInstructionWithAccounts(instruction);
+            return { instructionType: RecordInstruction.DeleteKey, ...parseDeleteKeyInstruction(instruction) };
+        }
+        case RecordInstruction.Migrate: {
+            assertIsInstructionWithAccounts(instruction);
+            return { instructionType: RecordInstruction.Migrate, ...parseMigrateInstruction(instruction) };
+        }
+        case RecordInstruction.InitializeAligned: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: RecordInstruction.InitializeAligned,
+                ...parseInitializeAlignedInstruction(instruction),
+            };
+        }
+        case RecordInstruction.InitializeChecked: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: RecordInstruction.InitializeChecked,
+                ...parseInitializeCheckedInstruction(instruction),
+            };
+        }
+        case RecordInstruction.SetAuthorityChecked: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: RecordInstruction.SetAuthorityChecked,
+                ...parseSetAuthorityCheckedInstruction(instruction),
+            };
+        }
+        case RecordInstruction.InitializeSponsored: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: RecordInstruction.InitializeSponsored,
+                ...parseInitializeSponsoredInstruction(instruction),
+            };
+        }
+        case RecordInstruction.ReallocateFunded: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: RecordInstruction.ReallocateFunded,
+                ...parseReallocateFundedInstruction(instruction),
+            };
+        }
+        case RecordInstruction.SetExpiry: {
+            assertIsInstructionWithAccounts(instruction);
+            return { instructionType: RecordInstruction.SetExpiry, ...parseSetExpiryInstruction(instruction) };
+        }
+        case RecordInstruction.CloseExpired: {
+            assertIsInstructionWithAccounts(instruction);
+            return { instructionType: RecordInstruction.CloseExpired, ...parseCloseExpiredInstruction(instruction) };
+        }
+        case RecordInstruction.SetWritePolicy: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: RecordInstruction.SetWritePolicy,
+                ...parseSetWritePolicyInstruction(instruction),
+            };
+        }
+        case RecordInstruction.RegisterSessionKey: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: RecordInstruction.RegisterSessionKey,
+                ...parseRegisterSessionKeyInstruction(instruction),
+            };
+        }
+        case RecordInstruction.RevokeSessionKey: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: RecordInstruction.RevokeSessionKey,
+                ...parseRevokeSessionKeyInstruction(instruction),
+            };
+        }
+        case RecordInstruction.SetTokenGatedAuthority: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: RecordInstruction.SetTokenGatedAuthority,
+                ...parseSetTokenGatedAuthorityInstruction(instruction),
+            };
+        }
+        case RecordInstruction.WriteSigned: {
+            assertIsInstructionWithAccounts(instruction);
+            return { instructionType: RecordInstruction.WriteSigned, ...parseWriteSignedInstruction(instruction) };
+        }
         default:
             throw new SolanaError(SOLANA_ERROR__PROGRAM_CLIENTS__UNRECOGNIZED_INSTRUCTION_TYPE, {
                 instructionType: instructionType as string,
@@ -169,6 +618,75 @@ export type RecordPluginInstructions = {
         input: CloseAccountInput,
     ) => ReturnType<typeof getCloseAccountInstruction> & SelfPlanAndSendFunctions;
     reallocate: (input: ReallocateInput) => ReturnType<typeof getReallocateInstruction> & SelfPlanAndSendFunctions;
+    writeIfEqual: (
+        input: WriteIfEqualInput,
+    ) => ReturnType<typeof getWriteIfEqualInstruction> & SelfPlanAndSendFunctions;
+    writeMultiple: (
+        input: WriteMultipleInput,
+    ) => ReturnType<typeof getWriteMultipleInstruction> & SelfPlanAndSendFunctions;
+    commit: (input: CommitInput) => ReturnType<typeof getCommitInstruction> & SelfPlanAndSendFunctions;
+    initializeWithMode: (
+        input: InitializeWithModeInput,
+    ) => ReturnType<typeof getInitializeWithModeInstruction> & SelfPlanAndSendFunctions;
+    initializeRingBuffer: (
+        input: InitializeRingBufferInput,
+    ) => ReturnType<typeof getInitializeRingBufferInstruction> & SelfPlanAndSendFunctions;
+    pushEntry: (input: PushEntryInput) => ReturnType<typeof getPushEntryInstruction> & SelfPlanAndSendFunctions;
+    initializeMerkleAccumulator: (
+        input: InitializeMerkleAccumulatorInput,
+    ) => ReturnType<typeof getInitializeMerkleAccumulatorInstruction> & SelfPlanAndSendFunctions;
+    appendLeaf: (input: AppendLeafInput) => ReturnType<typeof getAppendLeafInstruction> & SelfPlanAndSendFunctions;
+    verifyLeaf: (input: VerifyLeafInput) => ReturnType<typeof getVerifyLeafInstruction> & SelfPlanAndSendFunctions;
+    appendShard: (input: AppendShardInput) => ReturnType<typeof getAppendShardInstruction> & SelfPlanAndSendFunctions;
+    replaceShard: (
+        input: ReplaceShardInput,
+    ) => ReturnType<typeof getReplaceShardInstruction> & SelfPlanAndSendFunctions;
+    initializeContent: (
+        input: InitializeContentInput,
+    ) => ReturnType<typeof getInitializeContentInstruction> & SelfPlanAndSendFunctions;
+    finalizeContent: (
+        input: FinalizeContentInput,
+    ) => ReturnType<typeof getFinalizeContentInstruction> & SelfPlanAndSendFunctions;
+    registerName: (
+        input: RegisterNameInput,
+    ) => ReturnType<typeof getRegisterNameInstruction> & SelfPlanAndSendFunctions;
+    repointName: (input: RepointNameInput) => ReturnType<typeof getRepointNameInstruction> & SelfPlanAndSendFunctions;
+    releaseName: (input: ReleaseNameInput) => ReturnType<typeof getReleaseNameInstruction> & SelfPlanAndSendFunctions;
+    setKey: (input: SetKeyInput) => ReturnType<typeof getSetKeyInstruction> & SelfPlanAndSendFunctions;
+    deleteKey: (input: DeleteKeyInput) => ReturnType<typeof getDeleteKeyInstruction> & SelfPlanAndSendFunctions;
+    migrate: (input: MigrateInput) => ReturnType<typeof getMigrateInstruction> & SelfPlanAndSendFunctions;
+    initializeAligned: (
+        input: InitializeAlignedInput,
+    ) => ReturnType<typeof getInitializeAlignedInstruction> & SelfPlanAndSendFunctions;
+    initializeChecked: (
+        input: InitializeCheckedInput,
+    ) => ReturnType<typeof getInitializeCheckedInstruction> & SelfPlanAndSendFunctions;
+    setAuthorityChecked: (
+        input: SetAuthorityCheckedInput,
+    ) => ReturnType<typeof getSetAuthorityCheckedInstruction> & SelfPlanAndSendFunctions;
+    initializeSponsored: (
+        input: InitializeSponsoredInput,
+    ) => ReturnType<typeof getInitializeSponsoredInstruction> & SelfPlanAndSendFunctions;
+    reallocateFunded: (
+        input: ReallocateFundedInput,
+    ) => ReturnType<typeof getReallocateFundedInstruction> & SelfPlanAndSendFunctions;
+    setExpiry: (input: SetExpiryInput) => ReturnType<typeof getSetExpiryInstruction> & SelfPlanAndSendFunctions;
+    closeExpired: (
+        input: CloseExpiredInput,
+    ) => ReturnType<typeof getCloseExpiredInstruction> & SelfPlanAndSendFunctions;
+    setWritePolicy: (
+        input: SetWritePolicyInput,
+    ) => ReturnType<typeof getSetWritePolicyInstruction> & SelfPlanAndSendFunctions;
+    registerSessionKey: (
+        input: RegisterSessionKeyInput,
+    ) => ReturnType<typeof getRegisterSessionKeyInstruction> & SelfPlanAndSendFunctions;
+    revokeSessionKey: (
+        input: RevokeSessionKeyInput,
+    ) => ReturnType<typeof getRevokeSessionKeyInstruction> & SelfPlanAndSendFunctions;
+    setTokenGatedAuthority: (
+        input: SetTokenGatedAuthorityInput,
+    ) => ReturnType<typeof getSetTokenGatedAuthorityInstruction> & SelfPlanAndSendFunctions;
+    writeSigned: (input: WriteSignedInput) => ReturnType<typeof getWriteSignedInstruction> & SelfPlanAndSendFunctions;
 };
 
 export type RecordPluginRequirements = ClientWithRpc<GetAccountInfoApi & GetMultipleAccountsApi> &
@@ -186,6 +704,49 @@ export function recordProgram() {
                     setAuthority: input => addSelfPlanAndSendFunctions(client, getSetAuthorityInstruction(input)),
                     closeAccount: input => addSelfPlanAndSendFunctions(client, getCloseAccountInstruction(input)),
                     reallocate: input => addSelfPlanAndSendFunctions(client, getReallocateInstruction(input)),
+                    writeIfEqual: input => addSelfPlanAndSendFunctions(client, getWriteIfEqualInstruction(input)),
+                    writeMultiple: input => addSelfPlanAndSendFunctions(client, getWriteMultipleInstruction(input)),
+                    commit: input => addSelfPlanAndSendFunctions(client, getCommitInstruction(input)),
+                    initializeWithMode: input =>
+                        addSelfPlanAndSendFunctions(client, getInitializeWithModeInstruction(input)),
+                    initializeRingBuffer: input =>
+                        addSelfPlanAndSendFunctions(client, getInitializeRingBufferInstruction(input)),
+                    pushEntry: input => addSelfPlanAndSendFunctions(client, getPushEntryInstruction(input)),
+                    initializeMerkleAccumulator: input =>
+                        addSelfPlanAndSendFunctions(client, getInitializeMerkleAccumulatorInstruction(input)),
+                    appendLeaf: input => addSelfPlanAndSendFunctions(client, getAppendLeafInstruction(input)),
+                    verifyLeaf: input => addSelfPlanAndSendFunctions(client, getVerifyLeafInstruction(input)),
+                    appendShard: input => addSelfPlanAndSendFunctions(client, getAppendShardInstruction(input)),
+                    replaceShard: input => addSelfPlanAndSendFunctions(client, getReplaceShardInstruction(input)),
+                    initializeContent: input =>
+                        addSelfPlanAndSendFunctions(client, getInitializeContentInstruction(input)),
+                    finalizeContent: input => addSelfPlanAndSendFunctions(client, getFinalizeContentInstruction(input)),
+                    registerName: input => addSelfPlanAndSendFunctions(client, getRegisterNameInstruction(input)),
+                    repointName: input => addSelfPlanAndSendFunctions(client, getRepointNameInstruction(input)),
+                    releaseName: input => addSelfPlanAndSendFunctions(client, getReleaseNameInstruction(input)),
+                    setKey: input => addSelfPlanAndSendFunctions(client, getSetKeyInstruction(input)),
+                    deleteKey: input => addSelfPlanAndSendFunctions(client, getDeleteKeyInstruction(input)),
+                    migrate: input => addSelfPlanAndSendFunctions(client, getMigrateInstruction(input)),
+                    initializeAligned: input =>
+                        addSelfPlanAndSendFunctions(client, getInitializeAlignedInstruction(input)),
+                    initializeChecked: input =>
+                        addSelfPlanAndSendFunctions(client, getInitializeCheckedInstruction(input)),
+                    setAuthorityChecked: input =>
+                        addSelfPlanAndSendFunctions(client, getSetAuthorityCheckedInstruction(input)),
+                    initializeSponsored: input =>
+                        addSelfPlanAndSendFunctions(client, getInitializeSponsoredInstruction(input)),
+                    reallocateFunded: input =>
+                        addSelfPlanAndSendFunctions(client, getReallocateFundedInstruction(input)),
+                    setExpiry: input => addSelfPlanAndSendFunctions(client, getSetExpiryInstruction(input)),
+                    closeExpired: input => addSelfPlanAndSendFunctions(client, getCloseExpiredInstruction(input)),
+                    setWritePolicy: input => addSelfPlanAndSendFunctions(client, getSetWritePolicyInstruction(input)),
+                    registerSessionKey: input =>
+                        addSelfPlanAndSendFunctions(client, getRegisterSessionKeyInstruction(input)),
+                    revokeSessionKey: input =>
+                        addSelfPlanAndSendFunctions(client, getRevokeSessionKeyInstruction(input)),
+                    setTokenGatedAuthority: input =>
+                        addSelfPlanAndSendFunctions(client, getSetTokenGatedAuthorityInstruction(input)),
+                    writeSigned: input => addSelfPlanAndSendFunctions(client, getWriteSignedInstruction(input)),
                 },
                 identifyAccount: identifyRecordAccount,
                 identifyInstruction: identifyRecordInstruction,
@@ -3,8 +3,8 @@
 #![cfg(all(target_os = "solana", not(feature = "no-entrypoint")))]
 
 use {
-    solana_account_info::AccountInfo, solana_address::Address, solana_program_error::ProgramResult,
-    solana_security_txt::security_txt,
+    crate::error::RecordError, solana_account_info::AccountInfo, solana_address::Address,
+    solana_msg::msg, solana_program_error::ProgramResult, solana_security_txt::security_txt,
 };
 
 solana_program_entrypoint::entrypoint!(process_instruction);
@@ -13,7 +13,13 @@ fn process_instruction(
     accounts: &[AccountInfo],
     instruction_data: &[u8],
 ) -> ProgramResult {
-    crate::processor::process_instruction(program_id, accounts, instruction_data)
+    if let Err(error) =
+        crate::processor::process_instruction(program_id, accounts, instruction_data)
+    {
+        msg!(error.to_str::<RecordError>());
+        return Err(error);
+    }
+    Ok(())
 }
 
 security_txt! {
@@ -1,20 +1,358 @@
 //! Error types
 
-use {num_derive::FromPrimitive, solana_program_error::ProgramError, thiserror::Error};
+use {
+    num_derive::FromPrimitive,
+    num_traits::FromPrimitive,
+    solana_program_error::{ProgramError, ToStr},
+    thiserror::Error,
+};
 
 /// Errors that may be returned by the program.
-#[derive(Clone, Debug, Eq, Error, FromPrimitive, PartialEq)]
+///
+/// Each variant is returned as `ProgramError::Custom` with its discriminant
+/// as the code. Codes are stable: new variants are only ever appended.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, Eq, Error, FromPrimitive, PartialEq)]
 pub enum RecordError {
     /// Incorrect authority provided on update or delete
     #[error("Incorrect authority provided on update or delete")]
-    IncorrectAuthority,
+    IncorrectAuthority = 0,
 
     /// Calculation overflow
     #[error("Calculation overflow")]
-    Overflow,
+    Overflow = 1,
+
+    /// Record data does not match the expected bytes
+    #[error("Record data does not match the expected bytes")]
+    ExpectedDataMismatch = 2,
+
+    /// Buffer payload does not match the expected hash
+    #[error("Buffer payload does not match the expected hash")]
+    HashMismatch = 3,
+
+    /// Append-only record data cannot be rewritten or shrunk
+    #[error("Append-only record data cannot be rewritten or shrunk")]
+    AppendOnlyViolation = 4,
+
+    /// Instruction not supported by the record's mode
+    #[error("Instruction not supported by the record's mode")]
+    InvalidRecordMode = 5,
+
+    /// Merkle accumulator cannot hold any more leaves
+    #[error("Merkle accumulator cannot hold any more leaves")]
+    MerkleTreeFull = 6,
+
+    /// Merkle inclusion proof does not match the record's root
+    #[error("Merkle inclusion proof does not match the record's root")]
+    InvalidMerkleProof = 7,
+
+    /// Shard does not match the manifest entry
+    #[error("Shard does not match the manifest entry")]
+    ShardMismatch = 8,
+
+    /// Name must be 1 to 32 bytes of UTF-8
+    #[error("Name must be 1 to 32 bytes of UTF-8")]
+    InvalidName = 9,
+
+    /// Key not found in the key-value record
+    #[error("Key not found in the key-value record")]
+    KeyNotFound = 10,
+
+    /// Extension not found in the record's extension region
+    #[error("Extension not found in the record's extension region")]
+    ExtensionNotFound = 11,
+
+    /// Account is not owned by the record program
+    #[error("Account is not owned by the record program")]
+    IncorrectOwner = 12,
+
+    /// Account must be writable
+    #[error("Account must be writable")]
+    AccountNotWritable = 13,
+
+    /// The same account was provided for more than one role
+    #[error("The same account was provided for more than one role")]
+    DuplicateAccount = 14,
+
+    /// Address cannot be used as the record authority
+    #[error("Address cannot be used as the record authority")]
+    InvalidAuthority = 15,
+
+    /// Record account not initialized
+    #[error("Record account not initialized")]
+    UninitializedRecord = 16,
+
+    /// Record account already initialized
+    #[error("Record account already initialized")]
+    AlreadyInitialized = 17,
+
+    /// Record account too small to hold its header
+    #[error("Record account too small to hold its header")]
+    HeaderTooSmall = 18,
+
+    /// Write or checked range extends past the end of the record
+    #[error("Write or checked range extends past the end of the record")]
+    WriteOutOfBounds = 19,
+
+    /// Record content is finalized and cannot be modified
+    #[error("Record content is finalized and cannot be modified")]
+    RecordFrozen = 20,
+
+    /// Lamports must be refunded to the record's rent beneficiary
+    #[error("Lamports must be refunded to the record's rent beneficiary")]
+    IncorrectBeneficiary = 21,
+
+    /// Record has not expired yet
+    #[error("Record has not expired yet")]
+    RecordNotExpired = 22,
+
+    /// Record cannot be written outside its write window
+    #[error("Record cannot be written outside its write window")]
+    WriteOutsideWindow = 23,
+
+    /// Record was written too recently to be written again
+    #[error("Record was written too recently to be written again")]
+    WriteRateLimited = 24,
+
+    /// Session key has expired
+    #[error("Session key has expired")]
+    SessionKeyExpired = 25,
+
+    /// Write exceeds the session key's byte budget
+    #[error("Write exceeds the session key's byte budget")]
+    SessionBudgetExceeded = 26,
+
+    /// Record cannot hold any more session keys
+    #[error("Record cannot hold any more session keys")]
+    SessionKeysFull = 27,
+
+    /// Signer does not hold the token gating the record
+    #[error("Signer does not hold the token gating the record")]
+    TokenNotHeld = 28,
+
+    /// Write is not covered by a matching Ed25519 signature
+    #[error("Write is not covered by a matching Ed25519 signature")]
+    InvalidWriteSignature = 29,
+
+    /// Signed write nonce does not match the record's nonce
+    #[error("Signed write nonce does not match the record's nonce")]
+    NonceMismatch = 30,
+
+    /// Record already uses the extended header
+    #[error("Record already uses the extended header")]
+    AlreadyMigrated = 31,
+
+    /// Shard index is past the end of the manifest
+    #[error("Shard index is past the end of the manifest")]
+    ShardIndexOutOfRange = 32,
+
+    /// Record cannot be shrunk below its entries
+    #[error("Record cannot be shrunk below its entries")]
+    ShrinkBelowEntries = 33,
+
+    /// Session key is not registered for the record
+    #[error("Session key is not registered for the record")]
+    SessionKeyNotFound = 34,
+
+    /// Write window must not end before it starts
+    #[error("Write window must not end before it starts")]
+    InvalidWriteWindow = 35,
+
+    /// Entry does not match the ring buffer entry size
+    #[error("Entry does not match the ring buffer entry size")]
+    EntrySizeMismatch = 36,
+
+    /// Ring buffer entry size must be non-zero
+    #[error("Ring buffer entry size must be non-zero")]
+    InvalidEntrySize = 37,
+
+    /// Record account too small to hold a ring buffer entry
+    #[error("Record account too small to hold a ring buffer entry")]
+    RingBufferTooSmall = 38,
+
+    /// Record account too small to hold the Merkle accumulator
+    #[error("Record account too small to hold the Merkle accumulator")]
+    MerkleAccumulatorTooSmall = 39,
+
+    /// Manifest record too small to hold its shard entries
+    #[error("Manifest record too small to hold its shard entries")]
+    ManifestTooSmall = 40,
+
+    /// Key-value record too small to hold its entries
+    #[error("Key-value record too small to hold its entries")]
+    KeyValueTooSmall = 41,
+
+    /// Token-gated records need a rent beneficiary to be closed on expiry
+    #[error("Token-gated records need a rent beneficiary to be closed on expiry")]
+    MissingRentBeneficiary = 42,
+
+    /// Record account passed without any write
+    #[error("Record account passed without any write")]
+    UnwrittenRecord = 43,
 }
 impl From<RecordError> for ProgramError {
     fn from(e: RecordError) -> Self {
         ProgramError::Custom(e as u32)
     }
 }
+
+impl TryFrom<u32> for RecordError {
+    type Error = ProgramError;
+
+    fn try_from(code: u32) -> Result<Self, Self::Error> {
+        Self::from_u32(code).ok_or(ProgramError::InvalidArgument)
+    }
+}
+
+impl ToStr for RecordError {
+    fn to_str(&self) -> &'static str {
+        match self {
+            RecordError::IncorrectAuthority => {
+                "Error: Incorrect authority provided on update or delete"
+            }
+            RecordError::Overflow => "Error: Calculation overflow",
+            RecordError::ExpectedDataMismatch => {
+                "Error: Record data does not match the expected bytes"
+            }
+            RecordError::HashMismatch => "Error: Buffer payload does not match the expected hash",
+            RecordError::AppendOnlyViolation => {
+                "Error: Append-only record data cannot be rewritten or shrunk"
+            }
+            RecordError::InvalidRecordMode => {
+                "Error: Instruction not supported by the record's mode"
+            }
+            RecordError::MerkleTreeFull => "Error: Merkle accumulator cannot hold any more leaves",
+            RecordError::InvalidMerkleProof => {
+                "Error: Merkle inclusion proof does not match the record's root"
+            }
+            RecordError::ShardMismatch => "Error: Shard does not match the manifest entry",
+            RecordError::InvalidName => "Error: Name must be 1 to 32 bytes of UTF-8",
+            RecordError::KeyNotFound => "Error: Key not found in the key-value record",
+            RecordError::ExtensionNotFound => {
+                "Error: Extension not found in the record's extension region"
+            }
+            RecordError::IncorrectOwner => "Error: Account is not owned by the record program",
+            RecordError::AccountNotWritable => "Error: Account must be writable",
+            RecordError::DuplicateAccount => {
+                "Error: The same account was provided for more than one role"
+            }
+            RecordError::InvalidAuthority => {
+                "Error: Address cannot be used as the record authority"
+            }
+            RecordError::UninitializedRecord => "Error: Record account not initialized",
+            RecordError::AlreadyInitialized => "Error: Record account already initialized",
+            RecordError::HeaderTooSmall => "Error: Record account too small to hold its header",
+            RecordError::WriteOutOfBounds => {
+                "Error: Write or checked range extends past the end of the record"
+            }
+            RecordError::RecordFrozen => {
+                "Error: Record content is finalized and cannot be modified"
+            }
+            RecordError::IncorrectBeneficiary => {
+                "Error: Lamports must be refunded to the record's rent beneficiary"
+            }
+            RecordError::RecordNotExpired => "Error: Record has not expired yet",
+            RecordError::WriteOutsideWindow => {
+                "Error: Record cannot be written outside its write window"
+            }
+            RecordError::WriteRateLimited => {
+                "Error: Record was written too recently to be written again"
+            }
+            RecordError::SessionKeyExpired => "Error: Session key has expired",
+            RecordError::SessionBudgetExceeded => {
+                "Error: Write exceeds the session key's byte budget"
+            }
+            RecordError::SessionKeysFull => "Error: Record cannot hold any more session keys",
+            RecordError::TokenNotHeld => "Error: Signer does not hold the token gating the record",
+            RecordError::InvalidWriteSignature => {
+                "Error: Write is not covered by a matching Ed25519 signature"
+            }
+            RecordError::NonceMismatch => {
+                "Error: Signed write nonce does not match the record's nonce"
+            }
+            RecordError::AlreadyMigrated => "Error: Record already uses the extended header",
+            RecordError::ShardIndexOutOfRange => {
+                "Error: Shard index is past the end of the manifest"
+            }
+            RecordError::ShrinkBelowEntries => "Error: Record cannot be shrunk below its entries",
+            RecordError::SessionKeyNotFound => {
+                "Error: Session key is not registered for the record"
+            }
+            RecordError::InvalidWriteWindow => "Error: Write window must not end before it starts",
+            RecordError::EntrySizeMismatch => {
+                "Error: Entry does not match the ring buffer entry size"
+            }
+            RecordError::InvalidEntrySize => "Error: Ring buffer entry size must be non-zero",
+            RecordError::RingBufferTooSmall => {
+                "Error: Record account too small to hold a ring buffer entry"
+            }
+            RecordError::MerkleAccumulatorTooSmall => {
+                "Error: Record account too small to hold the Merkle accumulator"
+            }
+            RecordError::ManifestTooSmall => {
+                "Error: Manifest record too small to hold its shard entries"
+            }
+            RecordError::KeyValueTooSmall => {
+                "Error: Key-value record too small to hold its entries"
+            }
+            RecordError::MissingRentBeneficiary => {
+                "Error: Token-gated records need a rent beneficiary to be closed on expiry"
+            }
+            RecordError::UnwrittenRecord => "Error: Record account passed without any write",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_codes_round_trip() {
+        assert_eq!(RecordError::IncorrectAuthority as u32, 0);
+        assert_eq!(RecordError::RecordFrozen as u32, 20);
+        assert_eq!(RecordError::IncorrectBeneficiary as u32, 21);
+        assert_eq!(RecordError::RecordNotExpired as u32, 22);
+        assert_eq!(RecordError::WriteOutsideWindow as u32, 23);
+        assert_eq!(RecordError::WriteRateLimited as u32, 24);
+        assert_eq!(RecordError::SessionKeyExpired as u32, 25);
+        assert_eq!(RecordError::SessionBudgetExceeded as u32, 26);
+        assert_eq!(RecordError::SessionKeysFull as u32, 27);
+        assert_eq!(RecordError::TokenNotHeld as u32, 28);
+        assert_eq!(RecordError::InvalidWriteSignature as u32, 29);
+        assert_eq!(RecordError::NonceMismatch as u32, 30);
+        assert_eq!(RecordError::AlreadyMigrated as u32, 31);
+        assert_eq!(RecordError::ShardIndexOutOfRange as u32, 32);
+        assert_eq!(RecordError::ShrinkBelowEntries as u32, 33);
+        assert_eq!(RecordError::SessionKeyNotFound as u32, 34);
+        assert_eq!(RecordError::InvalidWriteWindow as u32, 35);
+        assert_eq!(RecordError::EntrySizeMismatch as u32, 36);
+        assert_eq!(RecordError::InvalidEntrySize as u32, 37);
+        assert_eq!(RecordError::RingBufferTooSmall as u32, 38);
+        assert_eq!(RecordError::MerkleAccumulatorTooSmall as u32, 39);
+        assert_eq!(RecordError::ManifestTooSmall as u32, 40);
+        assert_eq!(RecordError::KeyValueTooSmall as u32, 41);
+        assert_eq!(RecordError::MissingRentBeneficiary as u32, 42);
+        assert_eq!(RecordError::UnwrittenRecord as u32, 43);
+        for code in 0..=RecordError::UnwrittenRecord as u32 {
+            let error = RecordError::try_from(code).unwrap();
+            assert_eq!(error as u32, code);
+            assert_eq!(
+                ProgramError::from(error).to_str::<RecordError>(),
+                error.to_str()
+            );
+        }
+        assert_eq!(
+            RecordError::try_from(RecordError::UnwrittenRecord as u32 + 1),
+            Err(ProgramError::InvalidArgument)
+        );
+        assert_eq!(
+            ProgramError::Custom(u32::MAX).to_str::<RecordError>(),
+            "Error: Unknown"
+        );
+        assert_eq!(
+            ProgramError::from(RecordError::WriteOutOfBounds).to_str::<RecordError>(),
+            "Error: Write or checked range extends past the end of the record"
+        );
+    }
+}
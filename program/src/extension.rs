@@ -0,0 +1,610 @@
+//! Extensions stored in the type-length-value region of records
+//!
+//! Records initialized with the extended header may hold a region of
+//! extensions between the header and the payload, its length given by
+//! [`RecordDataV2::extensions_length`]. Each extension is stored as a
+//! little-endian `u16` type, a little-endian `u16` value length, then the
+//! value. An entry of type [`ExtensionType::Uninitialized`], or any bytes too
+//! short to hold an entry, mark the free space at the end of the region.
+//! The region of a record is reached through [`RecordAccount::extensions`].
+//!
+//! [`RecordDataV2::extensions_length`]: crate::state::RecordDataV2::extensions_length
+//! [`RecordAccount::extensions`]: crate::state::RecordAccount::extensions
+
+use {
+    crate::error::RecordError,
+    bytemuck::{Pod, Zeroable},
+    num_derive::FromPrimitive,
+    num_traits::FromPrimitive,
+    solana_address::Address,
+    solana_program_error::ProgramError,
+    std::ops::Range,
+};
+
+/// Length of the type and length fields preceding each extension's value
+pub const TLV_HEADER_LEN: usize = 4;
+
+/// Maximum number of session keys a record can hold at once
+pub const MAX_SESSION_KEYS: usize = 4;
+
+/// Types of extensions a record can hold
+#[repr(u16)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive)]
+pub enum ExtensionType {
+    /// Free space in the extension region, never read as an extension
+    Uninitialized,
+    /// Account refunded when the record is closed, see [`RentBeneficiary`]
+    RentBeneficiary,
+    /// Slot after which anyone can close the record, see [`Expiry`]
+    Expiry,
+    /// Slots in which the record can be written, see [`WritePolicy`]
+    WritePolicy,
+    /// Keys allowed to write the record for a limited time, see
+    /// [`SessionKeys`]
+    SessionKeys,
+    /// Token program of the mint gating the record, see [`TokenGate`]
+    TokenGate,
+    /// Replay protection for signed writes, see [`WriteNonce`]
+    WriteNonce,
+}
+
+impl ExtensionType {
+    /// Length of the extension's value
+    pub fn value_len(self) -> usize {
+        match self {
+            ExtensionType::Uninitialized => 0,
+            ExtensionType::RentBeneficiary => size_of::<RentBeneficiary>(),
+            ExtensionType::Expiry => size_of::<Expiry>(),
+            ExtensionType::WritePolicy => size_of::<WritePolicy>(),
+            ExtensionType::SessionKeys => size_of::<SessionKeys>(),
+            ExtensionType::TokenGate => size_of::<TokenGate>(),
+            ExtensionType::WriteNonce => size_of::<WriteNonce>(),
+        }
+    }
+
+    /// Length of the extension's entry, including its type and length
+    pub fn tlv_len(self) -> usize {
+        TLV_HEADER_LEN.saturating_add(self.value_len())
+    }
+}
+
+/// Value of an extension, stored in the extension region of a record
+///
+/// Values are read in place, so they must only contain byte arrays or other
+/// types with an alignment of 1.
+pub trait Extension: Pod {
+    /// Type of the extension
+    const TYPE: ExtensionType;
+}
+
+/// Account that paid for a sponsored record, which must receive the record's
+/// lamports when it is closed
+///
+/// Set when the record is initialized with `InitializeSponsored`, and updated
+/// to the payer of each `ReallocateFunded`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct RentBeneficiary {
+    /// Account to refund on close
+    pub beneficiary: Address,
+}
+
+impl Extension for RentBeneficiary {
+    const TYPE: ExtensionType = ExtensionType::RentBeneficiary;
+}
+
+/// Slot from which anyone can close the record with `CloseExpired`, refunding
+/// its rent beneficiary, or its authority if it has none
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct Expiry {
+    /// Expiry slot, stored as a little-endian `u64`
+    pub slot: [u8; 8],
+}
+
+impl Expiry {
+    /// Create an expiry at the given slot
+    pub fn new(slot: u64) -> Self {
+        Self {
+            slot: slot.to_le_bytes(),
+        }
+    }
+
+    /// The expiry slot
+    pub fn slot(&self) -> u64 {
+        u64::from_le_bytes(self.slot)
+    }
+}
+
+impl Extension for Expiry {
+    const TYPE: ExtensionType = ExtensionType::Expiry;
+}
+
+/// Schedule and rate limit for writes to the record's data
+///
+/// Checked against the current slot by every instruction that modifies the
+/// record's payload. All slots are stored as little-endian `u64`s.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct WritePolicy {
+    /// First slot in which the record can be written
+    pub start_slot: [u8; 8],
+    /// Last slot in which the record can be written
+    pub end_slot: [u8; 8],
+    /// Minimum number of slots between two writes
+    pub min_slot_gap: [u8; 8],
+    /// First slot in which the next write is allowed, maintained by the
+    /// program
+    pub next_write_slot: [u8; 8],
+}
+
+impl WritePolicy {
+    /// Check that a write is allowed in `slot`, and record it for the rate
+    /// limit
+    pub fn record_write(&mut self, slot: u64) -> Result<(), RecordError> {
+        let start_slot = u64::from_le_bytes(self.start_slot);
+        let end_slot = u64::from_le_bytes(self.end_slot);
+        if slot < start_slot || slot > end_slot {
+            return Err(RecordError::WriteOutsideWindow);
+        }
+        if slot < u64::from_le_bytes(self.next_write_slot) {
+            return Err(RecordError::WriteRateLimited);
+        }
+        self.next_write_slot = slot
+            .saturating_add(u64::from_le_bytes(self.min_slot_gap))
+            .to_le_bytes();
+        Ok(())
+    }
+}
+
+impl Extension for WritePolicy {
+    const TYPE: ExtensionType = ExtensionType::WritePolicy;
+}
+
+/// Key delegated by the record authority to sign `Write` instructions until
+/// its expiry slot
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct SessionKey {
+    /// Delegated key, the default address for an unused entry
+    pub key: Address,
+    /// First slot in which the key is no longer accepted, stored as a
+    /// little-endian `u64`
+    pub expiry_slot: [u8; 8],
+    /// Number of bytes the key can still write, stored as a little-endian
+    /// `u64`, with `u64::MAX` meaning unlimited
+    pub remaining_bytes: [u8; 8],
+    /// Token holder that registered the key on a token-gated record, which
+    /// must still hold the token for the key to be accepted, or the default
+    /// address on other records
+    pub holder: Address,
+}
+
+impl SessionKey {
+    /// Whether the key has expired by `slot`
+    pub fn is_expired(&self, slot: u64) -> bool {
+        slot >= u64::from_le_bytes(self.expiry_slot)
+    }
+
+    /// Check that the key can write `length` bytes in `slot`, charging them
+    /// against its budget
+    pub fn authorize_write(&mut self, slot: u64, length: usize) -> Result<(), RecordError> {
+        if self.is_expired(slot) {
+            return Err(RecordError::SessionKeyExpired);
+        }
+        let remaining_bytes = u64::from_le_bytes(self.remaining_bytes);
+        if remaining_bytes != u64::MAX {
+            self.remaining_bytes = remaining_bytes
+                .checked_sub(length as u64)
+                .ok_or(RecordError::SessionBudgetExceeded)?
+                .to_le_bytes();
+        }
+        Ok(())
+    }
+}
+
+/// Session keys registered by the record authority with
+/// `RegisterSessionKey`
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct SessionKeys {
+    /// Registered keys, unused entries hold the default address
+    pub keys: [SessionKey; MAX_SESSION_KEYS],
+}
+
+impl SessionKeys {
+    /// Find the entry of a registered key
+    pub fn find_mut(&mut self, key: &Address) -> Option<&mut SessionKey> {
+        if *key == Address::default() {
+            return None;
+        }
+        self.keys.iter_mut().find(|session| session.key == *key)
+    }
+
+    /// Register a key, replacing its entry if it is already registered, or
+    /// else taking an unused or expired entry
+    pub fn register(&mut self, session_key: SessionKey, slot: u64) -> Result<(), RecordError> {
+        let index = self
+            .keys
+            .iter()
+            .position(|session| session.key == session_key.key)
+            .or_else(|| {
+                self.keys.iter().position(|session| {
+                    session.key == Address::default() || session.is_expired(slot)
+                })
+            })
+            .ok_or(RecordError::SessionKeysFull)?;
+        self.keys[index] = session_key;
+        Ok(())
+    }
+
+    /// Remove a registered key, returning whether it was registered
+    pub fn revoke(&mut self, key: &Address) -> bool {
+        match self.find_mut(key) {
+            Some(session) => {
+                *session = SessionKey::default();
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl Extension for SessionKeys {
+    const TYPE: ExtensionType = ExtensionType::SessionKeys;
+}
+
+/// Marks the record authority as a mint, so that the record is controlled by
+/// whoever holds one of its tokens
+///
+/// Set by `SetTokenGatedAuthority`, and cleared when the authority is
+/// changed again.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct TokenGate {
+    /// Token program owning the mint, the default address if the record is
+    /// not token-gated
+    pub token_program: Address,
+}
+
+impl TokenGate {
+    /// Whether the record authority is a mint
+    pub fn is_enabled(&self) -> bool {
+        self.token_program != Address::default()
+    }
+}
+
+impl Extension for TokenGate {
+    const TYPE: ExtensionType = ExtensionType::TokenGate;
+}
+
+/// Nonce the record authority must sign for the next `WriteSigned`,
+/// incremented by each signed write so that it cannot be replayed
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct WriteNonce {
+    /// Next nonce, stored as a little-endian `u64`
+    pub nonce: [u8; 8],
+}
+
+impl WriteNonce {
+    /// The next nonce
+    pub fn nonce(&self) -> u64 {
+        u64::from_le_bytes(self.nonce)
+    }
+}
+
+impl Extension for WriteNonce {
+    const TYPE: ExtensionType = ExtensionType::WriteNonce;
+}
+
+/// Parses the entry at `offset`, returning its raw type and value range, or
+/// `None` if the free space at the end of the region starts there
+fn entry_at(tlv_data: &[u8], offset: usize) -> Result<Option<(u16, Range<usize>)>, ProgramError> {
+    let Some(header) = tlv_data
+        .get(offset..)
+        .and_then(|entry| entry.first_chunk::<TLV_HEADER_LEN>())
+    else {
+        return Ok(None);
+    };
+    let extension_type = u16::from_le_bytes([header[0], header[1]]);
+    if extension_type == ExtensionType::Uninitialized as u16 {
+        return Ok(None);
+    }
+    let start = offset.saturating_add(TLV_HEADER_LEN);
+    let end = start.saturating_add(u16::from_le_bytes([header[2], header[3]]) as usize);
+    if end > tlv_data.len() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    Ok(Some((extension_type, start..end)))
+}
+
+/// Finds the value range of an extension, along with the start of the free
+/// space at the end of the region if the extension is not present
+fn find_extension(
+    tlv_data: &[u8],
+    extension_type: ExtensionType,
+) -> Result<Result<Range<usize>, usize>, ProgramError> {
+    let mut offset = 0;
+    while let Some((entry_type, value)) = entry_at(tlv_data, offset)? {
+        if entry_type == extension_type as u16 {
+            return Ok(Ok(value));
+        }
+        offset = value.end;
+    }
+    Ok(Err(offset))
+}
+
+/// Types of the extensions present in an extension region, in storage order
+pub fn get_extension_types(tlv_data: &[u8]) -> Result<Vec<ExtensionType>, ProgramError> {
+    let mut extension_types = vec![];
+    let mut offset = 0;
+    while let Some((entry_type, value)) = entry_at(tlv_data, offset)? {
+        extension_types
+            .push(ExtensionType::from_u16(entry_type).ok_or(ProgramError::InvalidAccountData)?);
+        offset = value.end;
+    }
+    Ok(extension_types)
+}
+
+/// Length of the extensions at the start of an extension region, after which
+/// its free space starts
+pub fn get_extensions_length(tlv_data: &[u8]) -> Result<usize, ProgramError> {
+    let mut offset = 0;
+    while let Some((_, value)) = entry_at(tlv_data, offset)? {
+        offset = value.end;
+    }
+    Ok(offset)
+}
+
+/// Get an extension from an extension region
+pub fn get_extension<V: Extension>(tlv_data: &[u8]) -> Result<&V, ProgramError> {
+    let value = find_extension(tlv_data, V::TYPE)?.map_err(|_| RecordError::ExtensionNotFound)?;
+    bytemuck::try_from_bytes(&tlv_data[value]).map_err(|_| ProgramError::InvalidAccountData)
+}
+
+/// Get a mutable extension from an extension region
+pub fn get_extension_mut<V: Extension>(tlv_data: &mut [u8]) -> Result<&mut V, ProgramError> {
+    let value = find_extension(tlv_data, V::TYPE)?.map_err(|_| RecordError::ExtensionNotFound)?;
+    bytemuck::try_from_bytes_mut(&mut tlv_data[value]).map_err(|_| ProgramError::InvalidAccountData)
+}
+
+/// Initialize an extension in the free space of an extension region,
+/// returning its zeroed value
+///
+/// Fails if the extension is already present or the free space is too small
+/// to hold it.
+pub fn init_extension<V: Extension>(tlv_data: &mut [u8]) -> Result<&mut V, ProgramError> {
+    let Err(start) = find_extension(tlv_data, V::TYPE)? else {
+        return Err(ProgramError::AccountAlreadyInitialized);
+    };
+    let value_len = u16::try_from(size_of::<V>()).map_err(|_| ProgramError::InvalidArgument)?;
+    let entry = start
+        .checked_add(TLV_HEADER_LEN)
+        .and_then(|value_start| value_start.checked_add(value_len as usize))
+        .and_then(|end| tlv_data.get_mut(start..end))
+        .ok_or(ProgramError::AccountDataTooSmall)?;
+    let (header, value) = entry.split_at_mut(TLV_HEADER_LEN);
+    header[..2].copy_from_slice(&(V::TYPE as u16).to_le_bytes());
+    header[2..].copy_from_slice(&value_len.to_le_bytes());
+    value.fill(0);
+    bytemuck::try_from_bytes_mut(value).map_err(|_| ProgramError::InvalidAccountData)
+}
+
+/// Get a mutable extension from an extension region, initializing it in the
+/// free space if it is not present yet
+pub fn get_or_init_extension<V: Extension>(tlv_data: &mut [u8]) -> Result<&mut V, ProgramError> {
+    if get_extension_types(tlv_data)?.contains(&V::TYPE) {
+        get_extension_mut(tlv_data)
+    } else {
+        init_extension(tlv_data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::state::{
+            tests::TEST_ADDRESS, NameRecord, RecordAccount, RecordData, RecordDataV2, RecordMode,
+        },
+    };
+
+    #[test]
+    fn extension_region_parsing() {
+        assert_eq!(get_extension_types(&[]).unwrap(), vec![]);
+        assert_eq!(get_extension_types(&[0; 8]).unwrap(), vec![]);
+        // bytes too short to hold an entry are free space
+        assert_eq!(get_extension_types(&[1, 0, 4]).unwrap(), vec![]);
+        // value extending past the region
+        assert_eq!(
+            get_extension_types(&[1, 0, 5, 0, 0, 0, 0, 0]).unwrap_err(),
+            ProgramError::InvalidAccountData
+        );
+        // unknown extension type
+        assert_eq!(
+            get_extension_types(&[0xff, 0xff, 0, 0]).unwrap_err(),
+            ProgramError::InvalidAccountData
+        );
+    }
+
+    #[test]
+    fn rent_beneficiary_extension() {
+        let beneficiary = Address::new_from_array([3; 32]);
+        let mut tlv_data = vec![0; ExtensionType::RentBeneficiary.tlv_len() + 2];
+        assert_eq!(
+            get_extension::<RentBeneficiary>(&tlv_data).unwrap_err(),
+            RecordError::ExtensionNotFound.into()
+        );
+        init_extension::<RentBeneficiary>(&mut tlv_data)
+            .unwrap()
+            .beneficiary = beneficiary;
+        assert_eq!(
+            get_extension_types(&tlv_data).unwrap(),
+            vec![ExtensionType::RentBeneficiary]
+        );
+        assert_eq!(
+            get_extension::<RentBeneficiary>(&tlv_data).unwrap(),
+            &RentBeneficiary { beneficiary }
+        );
+        assert_eq!(
+            init_extension::<RentBeneficiary>(&mut tlv_data).unwrap_err(),
+            ProgramError::AccountAlreadyInitialized
+        );
+        assert_eq!(
+            init_extension::<RentBeneficiary>(&mut [0; 8]).unwrap_err(),
+            ProgramError::AccountDataTooSmall
+        );
+    }
+
+    #[test]
+    fn get_or_init_expiry() {
+        let mut tlv_data = vec![0; ExtensionType::Expiry.tlv_len() + 3];
+        assert_eq!(get_extensions_length(&tlv_data).unwrap(), 0);
+        *get_or_init_extension::<Expiry>(&mut tlv_data).unwrap() = Expiry::new(42);
+        assert_eq!(
+            get_extensions_length(&tlv_data).unwrap(),
+            ExtensionType::Expiry.tlv_len()
+        );
+        let expiry = get_or_init_extension::<Expiry>(&mut tlv_data).unwrap();
+        assert_eq!(expiry.slot(), 42);
+        *expiry = Expiry::new(7);
+        assert_eq!(get_extension::<Expiry>(&tlv_data).unwrap().slot(), 7);
+        assert_eq!(
+            get_or_init_extension::<RentBeneficiary>(&mut tlv_data).unwrap_err(),
+            ProgramError::AccountDataTooSmall
+        );
+    }
+
+    #[test]
+    fn write_policy_window_and_rate_limit() {
+        let mut policy = WritePolicy {
+            start_slot: 10u64.to_le_bytes(),
+            end_slot: 20u64.to_le_bytes(),
+            min_slot_gap: 5u64.to_le_bytes(),
+            next_write_slot: [0; 8],
+        };
+        assert_eq!(policy.record_write(9), Err(RecordError::WriteOutsideWindow));
+        assert_eq!(policy.record_write(10), Ok(()));
+        assert_eq!(policy.record_write(14), Err(RecordError::WriteRateLimited));
+        assert_eq!(policy.record_write(15), Ok(()));
+        assert_eq!(policy.record_write(20), Ok(()));
+        assert_eq!(
+            policy.record_write(21),
+            Err(RecordError::WriteOutsideWindow)
+        );
+        assert_eq!(u64::from_le_bytes(policy.next_write_slot), 25);
+    }
+
+    #[test]
+    fn session_key_registration() {
+        let session_key = |seed, expiry_slot: u64| SessionKey {
+            key: Address::new_from_array([seed; 32]),
+            expiry_slot: expiry_slot.to_le_bytes(),
+            remaining_bytes: u64::MAX.to_le_bytes(),
+            holder: Address::default(),
+        };
+        let mut sessions = SessionKeys::default();
+        for seed in 1..=MAX_SESSION_KEYS as u8 {
+            sessions.register(session_key(seed, 10), 0).unwrap();
+        }
+        assert_eq!(
+            sessions.register(session_key(9, 10), 0),
+            Err(RecordError::SessionKeysFull)
+        );
+        // re-registering a key updates its entry
+        sessions.register(session_key(1, 20), 0).unwrap();
+        let key = Address::new_from_array([1; 32]);
+        assert_eq!(
+            sessions.find_mut(&key).unwrap().expiry_slot,
+            20u64.to_le_bytes()
+        );
+        // expired keys are replaced
+        sessions.register(session_key(9, 30), 10).unwrap();
+        assert!(sessions
+            .find_mut(&Address::new_from_array([9; 32]))
+            .is_some());
+        assert!(sessions.revoke(&key));
+        assert!(!sessions.revoke(&key));
+        assert!(sessions.find_mut(&Address::default()).is_none());
+    }
+
+    #[test]
+    fn session_key_budget() {
+        let mut session = SessionKey {
+            key: Address::new_from_array([1; 32]),
+            expiry_slot: 10u64.to_le_bytes(),
+            remaining_bytes: 8u64.to_le_bytes(),
+            holder: Address::default(),
+        };
+        assert_eq!(session.authorize_write(0, 6), Ok(()));
+        assert_eq!(
+            session.authorize_write(0, 3),
+            Err(RecordError::SessionBudgetExceeded)
+        );
+        assert_eq!(session.authorize_write(9, 2), Ok(()));
+        assert_eq!(
+            session.authorize_write(10, 0),
+            Err(RecordError::SessionKeyExpired)
+        );
+
+        session.remaining_bytes = u64::MAX.to_le_bytes();
+        assert_eq!(session.authorize_write(0, usize::MAX), Ok(()));
+        assert_eq!(session.remaining_bytes, u64::MAX.to_le_bytes());
+    }
+
+    #[test]
+    fn record_extension_region() {
+        let mut account_data = bytemuck::bytes_of(&RecordData {
+            version: RecordData::CURRENT_VERSION,
+            authority: TEST_ADDRESS,
+        })
+        .to_vec();
+        account_data.extend_from_slice(&[1; 8]);
+        assert!(RecordAccount::unpack(account_data.as_slice())
+            .unwrap()
+            .extensions()
+            .is_empty());
+
+        let name_data = bytemuck::bytes_of(&NameRecord {
+            account_type: NameRecord::ACCOUNT_TYPE,
+            authority: TEST_ADDRESS,
+            record: TEST_ADDRESS,
+        });
+        assert_eq!(
+            RecordAccount::unpack(name_data).unwrap_err(),
+            ProgramError::InvalidAccountData
+        );
+        assert_eq!(
+            RecordData::writable_start_index(name_data).unwrap_err(),
+            ProgramError::InvalidAccountData
+        );
+
+        let mut account_data = bytemuck::bytes_of(&RecordDataV2 {
+            version: RecordDataV2::VERSION,
+            authority: TEST_ADDRESS,
+            mode: RecordMode::Standard as u8,
+            high_water_mark: [0; 8],
+            extensions_length: 8u32.to_le_bytes(),
+            alignment: 1,
+        })
+        .to_vec();
+        account_data.extend_from_slice(&[0; 4]);
+        assert_eq!(
+            RecordAccount::unpack(account_data.as_slice()).unwrap_err(),
+            ProgramError::InvalidAccountData
+        );
+        account_data.extend_from_slice(&[0; 4]);
+        account_data.extend_from_slice(&[1; 8]);
+        assert_eq!(
+            RecordAccount::unpack(account_data.as_slice())
+                .unwrap()
+                .extensions(),
+            &[0; 8]
+        );
+        assert_eq!(
+            RecordData::writable_start_index(&account_data).unwrap(),
+            RecordDataV2::LEN + 8
+        );
+    }
+}
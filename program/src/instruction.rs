@@ -1,7 +1,9 @@
 //! Program instructions
 
 use {
-    crate::id,
+    crate::{get_content_address, get_name_address, id, state::RecordMode},
+    bytemuck::Pod,
+    num_traits::FromPrimitive,
     solana_address::Address,
     solana_instruction::{AccountMeta, Instruction},
     solana_program_error::ProgramError,
@@ -24,7 +26,8 @@ pub enum RecordInstruction<'a> {
     /// Accounts expected by this instruction:
     ///
     /// 0. `[writable]` Record account, must be previously initialized
-    /// 1. `[signer]` Current record authority
+    /// 1. `[signer]` Current record authority, or one of its unexpired
+    ///    session keys
     Write {
         /// Offset to start writing record, expressed as `u64`.
         offset: u64,
@@ -65,47 +68,721 @@ pub enum RecordInstruction<'a> {
         /// data
         data_length: u64,
     },
+
+    /// Write to the provided record account, only if the bytes at
+    /// `check_offset` currently match `expected`
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[writable]` Record account, must be previously initialized
+    /// 1. `[signer]` Current record authority
+    WriteIfEqual {
+        /// Offset of the bytes to compare, expressed as `u64`.
+        check_offset: u64,
+        /// Bytes that must currently be present at `check_offset`
+        expected: &'a [u8],
+        /// Offset to start writing record, expressed as `u64`.
+        write_offset: u64,
+        /// Data to replace the existing record data
+        data: &'a [u8],
+    },
+
+    /// Write to several record accounts sharing the same authority, applying
+    /// all of the writes or none of them
+    ///
+    /// The signer is checked once, against the authority every record must
+    /// share, so session keys are not accepted. Records gated by the same
+    /// mint are written by one of its token holders. `N` is one more than the
+    /// highest record index written, and every record account must receive
+    /// at least one write.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[signer]` Record authority shared by all written records
+    /// 1. ..`1+N` `[writable]` Record accounts, must be previously initialized
+    /// 2. `1+N` `[]` Token account of the signer, if the records are
+    ///    token-gated
+    WriteMultiple {
+        /// Writes to apply, in order
+        writes: Vec<RecordWrite<'a>>,
+    },
+
+    /// Replace the payload of a record with the payload of a buffer record,
+    /// resizing the record to fit, and close the buffer record
+    ///
+    /// Any additional lamports needed to keep the record rent-exempt at its
+    /// new size must be transferred to it beforehand. Since the record can
+    /// only grow by a limited amount per instruction, large records should
+    /// be grown with `Reallocate` before committing.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[writable]` Record account, must be previously initialized
+    /// 1. `[writable]` Buffer record account, must be previously initialized
+    /// 2. `[signer]` Authority of both the record and the buffer
+    /// 3. `[writable]` Receiver of the buffer's lamports
+    Commit {
+        /// SHA-256 hash the buffer payload must match, if provided
+        expected_hash: Option<[u8; 32]>,
+    },
+
+    /// Create a new record with the given mode, using the extended
+    /// `RecordDataV2` header
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[writable]` Record account, must be uninitialized
+    /// 1. `[]` Record authority
+    InitializeWithMode {
+        /// Mode of the record, which cannot be changed afterwards
+        mode: RecordMode,
+    },
+
+    /// Create a new ring buffer record, holding as many entries of the given
+    /// size as fit in the account after the ring buffer header
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[writable]` Record account, must be uninitialized
+    /// 1. `[]` Record authority
+    InitializeRingBuffer {
+        /// Size of each entry
+        entry_size: u32,
+    },
+
+    /// Push an entry into a ring buffer record, overwriting the oldest entry
+    /// once the ring buffer is full
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[writable]` Ring buffer record account
+    /// 1. `[signer]` Current record authority
+    PushEntry {
+        /// Entry data, must be exactly the ring buffer's entry size
+        data: &'a [u8],
+    },
+
+    /// Create a new Merkle accumulator record, holding the root and leaf
+    /// count of a tree of the given depth along with the frontier needed to
+    /// append further leaves
+    ///
+    /// The record payload must be large enough to hold the `MerkleHeader`
+    /// followed by `depth` frontier nodes.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[writable]` Record account, must be uninitialized
+    /// 1. `[]` Record authority
+    InitializeMerkleAccumulator {
+        /// Depth of the tree, at most `merkle::MAX_DEPTH`
+        depth: u8,
+    },
+
+    /// Append a leaf to a Merkle accumulator record, updating its root
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[writable]` Merkle accumulator record account
+    /// 1. `[signer]` Current record authority
+    AppendLeaf {
+        /// Leaf node, usually computed with `merkle::hash_leaf`
+        leaf: [u8; 32],
+    },
+
+    /// Verify that a leaf is included in a Merkle accumulator record at the
+    /// given index, failing if the proof does not match the record's root
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[]` Merkle accumulator record account
+    VerifyLeaf {
+        /// Leaf node to verify
+        leaf: [u8; 32],
+        /// Index of the leaf in the tree
+        index: u64,
+        /// Sibling nodes from the leaf up to the root, one per level
+        proof: &'a [[u8; 32]],
+    },
+
+    /// Append a shard record to a manifest record, recording the shard's
+    /// payload length along with the given hash
+    ///
+    /// The manifest must have room for another `ShardEntry`, grown with
+    /// `Reallocate` beforehand if needed. Hashing large shards on-chain is
+    /// too expensive, so the hash is provided by the authority and checked
+    /// by readers when reassembling the payload.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[writable]` Manifest record account
+    /// 1. `[]` Shard record account, owned by the program
+    /// 2. `[signer]` Authority of both the manifest and the shard
+    AppendShard {
+        /// SHA-256 hash of the shard's payload
+        hash: [u8; 32],
+    },
+
+    /// Replace the shard at the given index of a manifest record
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[writable]` Manifest record account
+    /// 1. `[]` New shard record account, owned by the program
+    /// 2. `[signer]` Authority of both the manifest and the shard
+    ReplaceShard {
+        /// Index of the shard to replace
+        index: u32,
+        /// SHA-256 hash of the new shard's payload
+        hash: [u8; 32],
+    },
+
+    /// Create a content-addressed record at the address derived from the
+    /// hash of its payload, to be written and then finalized
+    ///
+    /// The record is created with room for `data_length` bytes of payload,
+    /// at most 10KiB since it is allocated through a cross-program
+    /// invocation. Larger payloads can be grown with `Reallocate` before
+    /// finalizing.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[writable, signer]` Funding account
+    /// 1. `[writable]` Record account, derived with `get_content_address`
+    /// 2. `[signer]` Record authority, allowed to write the payload until finalized
+    /// 3. `[]` System program
+    InitializeContent {
+        /// SHA-256 hash of the payload to be written
+        hash: [u8; 32],
+        /// Length of the payload
+        data_length: u64,
+    },
+
+    /// Verify that the payload of a content-addressed record matches the
+    /// hash its address is derived from, and make the record immutable
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[writable]` Content-addressed record account
+    /// 1. `[signer]` Current record authority
+    FinalizeContent {
+        /// SHA-256 hash the record's address was derived from
+        hash: [u8; 32],
+    },
+
+    /// Register a name for an authority, pointing at a record
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[writable, signer]` Funding account
+    /// 1. `[writable]` Name account, derived with `get_name_address`
+    /// 2. `[signer]` Authority the name is registered for
+    /// 3. `[]` Record account, owned by the program
+    /// 4. `[]` System program
+    RegisterName {
+        /// Name to register, 1 to 32 bytes of UTF-8
+        name: &'a str,
+    },
+
+    /// Point a registered name at another record
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[writable]` Name account
+    /// 1. `[signer]` Authority the name is registered for
+    /// 2. `[]` New record account, owned by the program
+    RepointName,
+
+    /// Release a registered name, closing the name account
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[writable]` Name account
+    /// 1. `[signer]` Authority the name is registered for
+    /// 2. `[writable]` Receiver of the name account's lamports
+    ReleaseName,
+
+    /// Set the value of a key in a key-value record, inserting the key if it
+    /// is not present
+    ///
+    /// The record must have room for the updated entry, grown with
+    /// `Reallocate` beforehand if needed.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[writable]` Key-value record account
+    /// 1. `[signer]` Current record authority
+    SetKey {
+        /// Key to set, 1 to 255 bytes
+        key: &'a [u8],
+        /// Value of the key
+        value: &'a [u8],
+    },
+
+    /// Delete a key from a key-value record
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[writable]` Key-value record account
+    /// 1. `[signer]` Current record authority
+    DeleteKey {
+        /// Key to delete
+        key: &'a [u8],
+    },
+
+    /// Upgrade the header of a record initialized without a mode to the
+    /// extended header, as a standard mode record without extensions
+    ///
+    /// The account is grown to make room for the extended header and the
+    /// payload is shifted after it, so the account must hold enough lamports
+    /// to stay rent-exempt at its new length.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[writable]` Record account
+    /// 1. `[signer]` Current record authority
+    Migrate,
+
+    /// Create a new record with the given mode whose payload starts at an
+    /// offset aligned to `alignment`, so `Pod` types can be read from the
+    /// payload in place
+    ///
+    /// The extension region is padded with free space up to the aligned
+    /// offset. Only the modes accepted by `InitializeWithMode` are
+    /// supported.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[writable]` Record account, must be uninitialized
+    /// 1. `[]` Record authority
+    InitializeAligned {
+        /// Mode of the record, which cannot be changed afterwards
+        mode: RecordMode,
+        /// Alignment of the payload offset, a power of two up to
+        /// `RecordDataV2::MAX_ALIGNMENT`
+        alignment: u8,
+    },
+
+    /// Create a new record, like `Initialize`, but requiring the authority
+    /// or the record account itself to sign so that the initialization
+    /// cannot be front-run with another authority
+    ///
+    /// The authority cannot be the default address, and the record account
+    /// must be rent-exempt.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[writable]` Record account, must be uninitialized, signer if the
+    ///    authority does not sign
+    /// 1. `[signer]` Record authority, signer if the record account does not
+    ///    sign
+    InitializeChecked,
+
+    /// Update the authority of the provided record account, like
+    /// `SetAuthority`, but requiring the new authority to co-sign
+    ///
+    /// The new authority cannot be the record account itself or the system
+    /// program.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[writable]` Record account, must be previously initialized
+    /// 1. `[signer]` Current record authority
+    /// 2. `[signer]` New record authority
+    SetAuthorityChecked,
+
+    /// Create a new record with the given mode, like `InitializeWithMode`,
+    /// whose lamports must be refunded to a rent beneficiary when it is
+    /// closed
+    ///
+    /// The beneficiary is stored in the record's extension region, so the
+    /// account must have room for the `RentBeneficiary` extension after the
+    /// extended header.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[writable]` Record account, must be uninitialized
+    /// 1. `[]` Record authority
+    /// 2. `[]` Rent beneficiary, usually the account that funded the record
+    InitializeSponsored {
+        /// Mode of the record, which cannot be changed afterwards
+        mode: RecordMode,
+    },
+
+    /// Reallocate additional space in a record account, like `Reallocate`,
+    /// with the payer topping up the record to stay rent-exempt and becoming
+    /// its rent beneficiary if it paid any rent
+    ///
+    /// The extension region is grown for a `RentBeneficiary` extension if it
+    /// has no room for one, with the payer funding the growth. Records
+    /// without the extended header must be migrated first. A rent beneficiary
+    /// other than the payer must sign to be replaced, and keeps its place if
+    /// no rent was needed.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[writable]` The record account to reallocate
+    /// 1. `[signer]` The account's owner
+    /// 2. `[writable, signer]` Payer of the additional rent
+    /// 3. `[]` System program
+    /// 4. `[signer]` Optional current rent beneficiary, required if the
+    ///    record has one other than the payer
+    ReallocateFunded {
+        /// New total length of the record payload
+        data_length: u64,
+    },
+
+    /// Set the slot from which anyone can close the record with
+    /// `CloseExpired`
+    ///
+    /// The expiry is stored in the record's extension region, which is grown
+    /// if it has no room for it, shifting the payload. The account must then
+    /// hold enough lamports to stay rent-exempt at its new length. Records
+    /// without the extended header must be migrated first.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[writable]` Record account
+    /// 1. `[signer]` Record authority
+    SetExpiry {
+        /// Slot from which the record can be closed
+        slot: u64,
+    },
+
+    /// Close an expired record, draining its lamports to its rent
+    /// beneficiary, or to its authority if it has none
+    ///
+    /// No signature is required, so anyone can clean up expired records.
+    /// Token-gated records must have a rent beneficiary, since their
+    /// authority is a mint.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[writable]` Record account, must have reached its expiry slot
+    /// 1. `[writable]` Rent beneficiary of the record, or its authority
+    CloseExpired,
+
+    /// Restrict writes to the record's payload to the slots from
+    /// `start_slot` to `end_slot` inclusive, at least `min_slot_gap` slots
+    /// apart
+    ///
+    /// The policy is stored in the record's extension region, which is grown
+    /// as for `SetExpiry`. Updating an existing policy keeps the slot of the
+    /// next allowed write. Records without the extended header must be
+    /// migrated first.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[writable]` Record account
+    /// 1. `[signer]` Record authority
+    SetWritePolicy {
+        /// First slot in which the record can be written
+        start_slot: u64,
+        /// Last slot in which the record can be written
+        end_slot: u64,
+        /// Minimum number of slots between two writes
+        min_slot_gap: u64,
+    },
+
+    /// Register a session key that can sign `Write` instructions in place of
+    /// the record authority until `expiry_slot`
+    ///
+    /// Registering a key again replaces its expiry and budget. The session
+    /// keys are stored in the record's extension region, which is grown as
+    /// for `SetExpiry`. Records without the extended header must be migrated
+    /// first. All session keys are revoked when the record authority changes.
+    /// On a token-gated record the key is tied to the registering token
+    /// holder: writes signed by the key must pass that holder's token account
+    /// after the other accounts, and are refused once it no longer holds the
+    /// token.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[writable]` Record account
+    /// 1. `[signer]` Record authority
+    /// 2. `[]` Session key
+    RegisterSessionKey {
+        /// First slot in which the key is no longer accepted
+        expiry_slot: u64,
+        /// Number of bytes the key can write, unlimited if `None`
+        byte_budget: Option<u64>,
+    },
+
+    /// Revoke a session key before its expiry
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[writable]` Record account
+    /// 1. `[signer]` Record authority
+    /// 2. `[]` Session key
+    RevokeSessionKey,
+
+    /// Hand control of the record to the holders of a mint's tokens
+    ///
+    /// The record authority is set to the mint. Afterwards, instructions
+    /// authorized by the record authority must be signed by the owner of a
+    /// token account holding at least one token of the mint, with that token
+    /// account passed after the instruction's other accounts. Both SPL Token
+    /// and Token-2022 mints are supported. Setting a new authority with
+    /// `SetAuthority` or `SetAuthorityChecked` removes the gate.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[writable]` Record account
+    /// 1. `[signer]` Record authority, or token holder if already gated
+    /// 2. `[]` Mint
+    /// 3. `[]` Token account of the signer, if already gated
+    SetTokenGatedAuthority,
+
+    /// Write to the record on behalf of its authority, who signed the write
+    /// off-chain
+    ///
+    /// The instruction immediately before this one must be an Ed25519
+    /// program instruction verifying a single signature by the record
+    /// authority over [`write_signed_message`], with the public key,
+    /// signature and message all stored in that instruction's own data.
+    /// Anyone can submit the write, since no account needs to sign. On a
+    /// token-gated record the signature must instead come from the owner of a
+    /// token account holding the mint, passed as account 2.
+    ///
+    /// The nonce must match the record's current nonce, starting at zero, and
+    /// is incremented by each signed write. The nonce is stored in the
+    /// record's extension region, which is grown as for `SetExpiry` on the
+    /// first signed write. If the record does not hold enough lamports for
+    /// the growth, a payer must be passed to top it up. Records without the
+    /// extended header must be migrated first.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[writable]` Record account, must be previously initialized
+    /// 1. `[]` Instructions sysvar
+    /// 2. `[]` Token account of the signer, if the record is token-gated
+    /// 3. `[writable, signer]` Optional payer of the rent for the nonce
+    /// 4. `[]` System program, if a payer is passed
+    WriteSigned {
+        /// Offset to start writing record, expressed as `u64`.
+        offset: u64,
+        /// Nonce signed by the record authority
+        nonce: u64,
+        /// Data to replace the existing record data
+        data: &'a [u8],
+    },
+}
+
+/// A single write applied by [`RecordInstruction::WriteMultiple`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecordWrite<'a> {
+    /// Index of the target record among the instruction's record accounts
+    pub record_index: u8,
+    /// Offset to start writing record, expressed as `u64`.
+    pub offset: u64,
+    /// Data to replace the existing record data
+    pub data: &'a [u8],
 }
 
 impl<'a> RecordInstruction<'a> {
     /// Unpacks a byte buffer into a [`RecordInstruction`].
     pub fn unpack(input: &'a [u8]) -> Result<Self, ProgramError> {
-        const U32_BYTES: usize = 4;
-        const U64_BYTES: usize = 8;
-
         let (&tag, rest) = input
             .split_first()
             .ok_or(ProgramError::InvalidInstructionData)?;
         Ok(match tag {
             0 => Self::Initialize,
             1 => {
-                let offset = rest
-                    .get(..U64_BYTES)
-                    .and_then(|slice| slice.try_into().ok())
-                    .map(u64::from_le_bytes)
-                    .ok_or(ProgramError::InvalidInstructionData)?;
-                let (length, data) = rest[U64_BYTES..].split_at(U32_BYTES);
-                let length = u32::from_le_bytes(
-                    length
-                        .try_into()
-                        .map_err(|_| ProgramError::InvalidInstructionData)?,
-                ) as usize;
-
-                Self::Write {
-                    offset,
-                    data: &data[..length],
-                }
+                let (offset, rest) = unpack_u64(rest)?;
+                let (data, _rest) = unpack_bytes(rest)?;
+                Self::Write { offset, data }
             }
             2 => Self::SetAuthority,
             3 => Self::CloseAccount,
             4 => {
-                let data_length = rest
-                    .get(..U64_BYTES)
+                let (data_length, _rest) = unpack_u64(rest)?;
+                Self::Reallocate { data_length }
+            }
+            5 => {
+                let (check_offset, rest) = unpack_u64(rest)?;
+                let (expected, rest) = unpack_bytes(rest)?;
+                let (write_offset, rest) = unpack_u64(rest)?;
+                let (data, _rest) = unpack_bytes(rest)?;
+                Self::WriteIfEqual {
+                    check_offset,
+                    expected,
+                    write_offset,
+                    data,
+                }
+            }
+            6 => {
+                let (&count, mut rest) = rest
+                    .split_first()
+                    .ok_or(ProgramError::InvalidInstructionData)?;
+                let mut writes = Vec::with_capacity(count as usize);
+                for _ in 0..count {
+                    let (&record_index, next) = rest
+                        .split_first()
+                        .ok_or(ProgramError::InvalidInstructionData)?;
+                    let (offset, next) = unpack_u64(next)?;
+                    let (data, next) = unpack_bytes(next)?;
+                    writes.push(RecordWrite {
+                        record_index,
+                        offset,
+                        data,
+                    });
+                    rest = next;
+                }
+                Self::WriteMultiple { writes }
+            }
+            7 => {
+                let expected_hash = match rest.split_first() {
+                    Some((0, _)) => None,
+                    Some((1, hash)) => Some(
+                        hash.get(..32)
+                            .and_then(|slice| slice.try_into().ok())
+                            .ok_or(ProgramError::InvalidInstructionData)?,
+                    ),
+                    _ => return Err(ProgramError::InvalidInstructionData),
+                };
+                Self::Commit { expected_hash }
+            }
+            8 => {
+                let mode = rest
+                    .first()
+                    .and_then(|&mode| RecordMode::from_u8(mode))
+                    .ok_or(ProgramError::InvalidInstructionData)?;
+                Self::InitializeWithMode { mode }
+            }
+            9 => {
+                let entry_size = rest
+                    .get(..4)
                     .and_then(|slice| slice.try_into().ok())
-                    .map(u64::from_le_bytes)
+                    .map(u32::from_le_bytes)
                     .ok_or(ProgramError::InvalidInstructionData)?;
-
-                Self::Reallocate { data_length }
+                Self::InitializeRingBuffer { entry_size }
+            }
+            10 => {
+                let (data, _rest) = unpack_bytes(rest)?;
+                Self::PushEntry { data }
+            }
+            11 => {
+                let &depth = rest.first().ok_or(ProgramError::InvalidInstructionData)?;
+                Self::InitializeMerkleAccumulator { depth }
+            }
+            12 => {
+                let (leaf, _rest) = unpack_node(rest)?;
+                Self::AppendLeaf { leaf }
+            }
+            13 => {
+                let (leaf, rest) = unpack_node(rest)?;
+                let (index, rest) = unpack_u64(rest)?;
+                let (&count, rest) = rest
+                    .split_first()
+                    .ok_or(ProgramError::InvalidInstructionData)?;
+                let proof = rest
+                    .get(..(count as usize).saturating_mul(32))
+                    .ok_or(ProgramError::InvalidInstructionData)?;
+                Self::VerifyLeaf {
+                    leaf,
+                    index,
+                    proof: bytemuck::cast_slice(proof),
+                }
+            }
+            14 => {
+                let (hash, _rest) = unpack_node(rest)?;
+                Self::AppendShard { hash }
+            }
+            15 => {
+                let index = rest
+                    .get(..4)
+                    .and_then(|slice| slice.try_into().ok())
+                    .map(u32::from_le_bytes)
+                    .ok_or(ProgramError::InvalidInstructionData)?;
+                let (hash, _rest) = unpack_node(&rest[4..])?;
+                Self::ReplaceShard { index, hash }
+            }
+            16 => {
+                let (hash, rest) = unpack_node(rest)?;
+                let (data_length, _rest) = unpack_u64(rest)?;
+                Self::InitializeContent { hash, data_length }
+            }
+            17 => {
+                let (hash, _rest) = unpack_node(rest)?;
+                Self::FinalizeContent { hash }
+            }
+            18 => {
+                let (name, _rest) = unpack_bytes(rest)?;
+                let name =
+                    std::str::from_utf8(name).map_err(|_| ProgramError::InvalidInstructionData)?;
+                Self::RegisterName { name }
+            }
+            19 => Self::RepointName,
+            20 => Self::ReleaseName,
+            21 => {
+                let (key, rest) = unpack_bytes(rest)?;
+                let (value, _rest) = unpack_bytes(rest)?;
+                Self::SetKey { key, value }
+            }
+            22 => {
+                let (key, _rest) = unpack_bytes(rest)?;
+                Self::DeleteKey { key }
+            }
+            23 => Self::Migrate,
+            24 => {
+                let (&mode, rest) = rest
+                    .split_first()
+                    .ok_or(ProgramError::InvalidInstructionData)?;
+                let mode = RecordMode::from_u8(mode).ok_or(ProgramError::InvalidInstructionData)?;
+                let alignment = *rest.first().ok_or(ProgramError::InvalidInstructionData)?;
+                Self::InitializeAligned { mode, alignment }
+            }
+            25 => Self::InitializeChecked,
+            26 => Self::SetAuthorityChecked,
+            27 => {
+                let mode = rest
+                    .first()
+                    .and_then(|&mode| RecordMode::from_u8(mode))
+                    .ok_or(ProgramError::InvalidInstructionData)?;
+                Self::InitializeSponsored { mode }
+            }
+            28 => {
+                let (data_length, _rest) = unpack_u64(rest)?;
+                Self::ReallocateFunded { data_length }
+            }
+            29 => {
+                let (slot, _rest) = unpack_u64(rest)?;
+                Self::SetExpiry { slot }
+            }
+            30 => Self::CloseExpired,
+            31 => {
+                let (start_slot, rest) = unpack_u64(rest)?;
+                let (end_slot, rest) = unpack_u64(rest)?;
+                let (min_slot_gap, _rest) = unpack_u64(rest)?;
+                Self::SetWritePolicy {
+                    start_slot,
+                    end_slot,
+                    min_slot_gap,
+                }
+            }
+            32 => {
+                let (expiry_slot, rest) = unpack_u64(rest)?;
+                let byte_budget = match rest.split_first() {
+                    Some((0, _)) => None,
+                    Some((1, budget)) => Some(unpack_u64(budget)?.0),
+                    _ => return Err(ProgramError::InvalidInstructionData),
+                };
+                Self::RegisterSessionKey {
+                    expiry_slot,
+                    byte_budget,
+                }
+            }
+            33 => Self::RevokeSessionKey,
+            34 => Self::SetTokenGatedAuthority,
+            35 => {
+                let (offset, rest) = unpack_u64(rest)?;
+                let (nonce, rest) = unpack_u64(rest)?;
+                let (data, _rest) = unpack_bytes(rest)?;
+                Self::WriteSigned {
+                    offset,
+                    nonce,
+                    data,
+                }
             }
             _ => return Err(ProgramError::InvalidInstructionData),
         })
@@ -128,84 +805,922 @@ impl<'a> RecordInstruction<'a> {
                 buf.push(4);
                 buf.extend_from_slice(&data_length.to_le_bytes());
             }
-        };
-        buf
+            Self::WriteIfEqual {
+                check_offset,
+                expected,
+                write_offset,
+                data,
+            } => {
+                buf.push(5);
+                buf.extend_from_slice(&check_offset.to_le_bytes());
+                buf.extend_from_slice(&(expected.len() as u32).to_le_bytes());
+                buf.extend_from_slice(expected);
+                buf.extend_from_slice(&write_offset.to_le_bytes());
+                buf.extend_from_slice(&(data.len() as u32).to_le_bytes());
+                buf.extend_from_slice(data);
+            }
+            Self::WriteMultiple { writes } => {
+                buf.push(6);
+                buf.push(writes.len() as u8);
+                for write in writes {
+                    buf.push(write.record_index);
+                    buf.extend_from_slice(&write.offset.to_le_bytes());
+                    buf.extend_from_slice(&(write.data.len() as u32).to_le_bytes());
+                    buf.extend_from_slice(write.data);
+                }
+            }
+            Self::Commit { expected_hash } => {
+                buf.push(7);
+                match expected_hash {
+                    Some(hash) => {
+                        buf.push(1);
+                        buf.extend_from_slice(hash);
+                    }
+                    None => buf.push(0),
+                }
+            }
+            Self::InitializeWithMode { mode } => {
+                buf.push(8);
+                buf.push(*mode as u8);
+            }
+            Self::InitializeRingBuffer { entry_size } => {
+                buf.push(9);
+                buf.extend_from_slice(&entry_size.to_le_bytes());
+            }
+            Self::PushEntry { data } => {
+                buf.push(10);
+                buf.extend_from_slice(&(data.len() as u32).to_le_bytes());
+                buf.extend_from_slice(data);
+            }
+            Self::InitializeMerkleAccumulator { depth } => {
+                buf.push(11);
+                buf.push(*depth);
+            }
+            Self::AppendLeaf { leaf } => {
+                buf.push(12);
+                buf.extend_from_slice(leaf);
+            }
+            Self::VerifyLeaf { leaf, index, proof } => {
+                buf.push(13);
+                buf.extend_from_slice(leaf);
+                buf.extend_from_slice(&index.to_le_bytes());
+                buf.push(proof.len() as u8);
+                buf.extend_from_slice(bytemuck::cast_slice(proof));
+            }
+            Self::AppendShard { hash } => {
+                buf.push(14);
+                buf.extend_from_slice(hash);
+            }
+            Self::ReplaceShard { index, hash } => {
+                buf.push(15);
+                buf.extend_from_slice(&index.to_le_bytes());
+                buf.extend_from_slice(hash);
+            }
+            Self::InitializeContent { hash, data_length } => {
+                buf.push(16);
+                buf.extend_from_slice(hash);
+                buf.extend_from_slice(&data_length.to_le_bytes());
+            }
+            Self::FinalizeContent { hash } => {
+                buf.push(17);
+                buf.extend_from_slice(hash);
+            }
+            Self::RegisterName { name } => {
+                buf.push(18);
+                buf.extend_from_slice(&(name.len() as u32).to_le_bytes());
+                buf.extend_from_slice(name.as_bytes());
+            }
+            Self::RepointName => buf.push(19),
+            Self::ReleaseName => buf.push(20),
+            Self::SetKey { key, value } => {
+                buf.push(21);
+                buf.extend_from_slice(&(key.len() as u32).to_le_bytes());
+                buf.extend_from_slice(key);
+                buf.extend_from_slice(&(value.len() as u32).to_le_bytes());
+                buf.extend_from_slice(value);
+            }
+            Self::DeleteKey { key } => {
+                buf.push(22);
+                buf.extend_from_slice(&(key.len() as u32).to_le_bytes());
+                buf.extend_from_slice(key);
+            }
+            Self::Migrate => buf.push(23),
+            Self::InitializeAligned { mode, alignment } => {
+                buf.push(24);
+                buf.push(*mode as u8);
+                buf.push(*alignment);
+            }
+            Self::InitializeChecked => buf.push(25),
+            Self::SetAuthorityChecked => buf.push(26),
+            Self::InitializeSponsored { mode } => {
+                buf.push(27);
+                buf.push(*mode as u8);
+            }
+            Self::ReallocateFunded { data_length } => {
+                buf.push(28);
+                buf.extend_from_slice(&data_length.to_le_bytes());
+            }
+            Self::SetExpiry { slot } => {
+                buf.push(29);
+                buf.extend_from_slice(&slot.to_le_bytes());
+            }
+            Self::CloseExpired => buf.push(30),
+            Self::SetWritePolicy {
+                start_slot,
+                end_slot,
+                min_slot_gap,
+            } => {
+                buf.push(31);
+                buf.extend_from_slice(&start_slot.to_le_bytes());
+                buf.extend_from_slice(&end_slot.to_le_bytes());
+                buf.extend_from_slice(&min_slot_gap.to_le_bytes());
+            }
+            Self::RegisterSessionKey {
+                expiry_slot,
+                byte_budget,
+            } => {
+                buf.push(32);
+                buf.extend_from_slice(&expiry_slot.to_le_bytes());
+                match byte_budget {
+                    Some(budget) => {
+                        buf.push(1);
+                        buf.extend_from_slice(&budget.to_le_bytes());
+                    }
+                    None => buf.push(0),
+                }
+            }
+            Self::RevokeSessionKey => buf.push(33),
+            Self::SetTokenGatedAuthority => buf.push(34),
+            Self::WriteSigned {
+                offset,
+                nonce,
+                data,
+            } => {
+                buf.push(35);
+                buf.extend_from_slice(&offset.to_le_bytes());
+                buf.extend_from_slice(&nonce.to_le_bytes());
+                buf.extend_from_slice(&(data.len() as u32).to_le_bytes());
+                buf.extend_from_slice(data);
+            }
+        };
+        buf
+    }
+}
+
+/// Unpacks a little-endian `u64` from the front of the input
+fn unpack_u64(input: &[u8]) -> Result<(u64, &[u8]), ProgramError> {
+    const U64_BYTES: usize = 8;
+    let value = input
+        .get(..U64_BYTES)
+        .and_then(|slice| slice.try_into().ok())
+        .map(u64::from_le_bytes)
+        .ok_or(ProgramError::InvalidInstructionData)?;
+    Ok((value, &input[U64_BYTES..]))
+}
+
+/// Unpacks a 32-byte hash or Merkle node from the front of the input
+fn unpack_node(input: &[u8]) -> Result<([u8; 32], &[u8]), ProgramError> {
+    let (node, rest) = input
+        .split_first_chunk::<32>()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+    Ok((*node, rest))
+}
+
+/// Unpacks a `u32` length-prefixed byte slice from the front of the input
+fn unpack_bytes(input: &[u8]) -> Result<(&[u8], &[u8]), ProgramError> {
+    const U32_BYTES: usize = 4;
+    let length = input
+        .get(..U32_BYTES)
+        .and_then(|slice| slice.try_into().ok())
+        .map(u32::from_le_bytes)
+        .ok_or(ProgramError::InvalidInstructionData)? as usize;
+    let rest = &input[U32_BYTES..];
+    if rest.len() < length {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    Ok(rest.split_at(length))
+}
+
+/// Create a `RecordInstruction::Initialize` instruction
+pub fn initialize(record_account: &Address, authority: &Address) -> Instruction {
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*record_account, false),
+            AccountMeta::new_readonly(*authority, false),
+        ],
+        data: RecordInstruction::Initialize.pack(),
+    }
+}
+
+/// Create a `RecordInstruction::Write` instruction
+pub fn write(record_account: &Address, signer: &Address, offset: u64, data: &[u8]) -> Instruction {
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*record_account, false),
+            AccountMeta::new_readonly(*signer, true),
+        ],
+        data: RecordInstruction::Write { offset, data }.pack(),
+    }
+}
+
+/// Create a `RecordInstruction::Write` instruction storing a `T` at the start
+/// of the record's payload, to be read back with `TypedRecord`
+pub fn write_typed<T: Pod>(record_account: &Address, signer: &Address, value: &T) -> Instruction {
+    write(record_account, signer, 0, bytemuck::bytes_of(value))
+}
+
+/// Create a `RecordInstruction::Write` instruction updating a single field,
+/// at `field_offset` within the value stored at the start of the record's
+/// payload
+///
+/// Prefer the [`write_field!`](crate::write_field) macro, which computes the
+/// offset and checks the value's type against the field.
+pub fn write_field<F: Pod>(
+    record_account: &Address,
+    signer: &Address,
+    field_offset: usize,
+    value: &F,
+) -> Instruction {
+    write(
+        record_account,
+        signer,
+        field_offset as u64,
+        bytemuck::bytes_of(value),
+    )
+}
+
+/// Create a `RecordInstruction::Write` instruction updating a single field of
+/// the `Pod` struct stored at the start of a record's payload
+///
+/// Fails to compile unless the value has the type of the field.
+///
+/// ```
+/// # use {bytemuck::{Pod, Zeroable}, solana_address::Address, spl_record::write_field};
+/// #[repr(C)]
+/// #[derive(Clone, Copy, Pod, Zeroable)]
+/// struct Config {
+///     admin: Address,
+///     threshold: u64,
+/// }
+///
+/// let record = Address::new_unique();
+/// let authority = Address::new_unique();
+/// let instruction = write_field!(&record, &authority, Config, threshold, 3u64);
+/// ```
+#[macro_export]
+macro_rules! write_field {
+    ($record_account:expr, $signer:expr, $type:ty, $field:ident, $value:expr) => {{
+        let value = $value;
+        let _ = |record: &$type| [record.$field, value];
+        $crate::instruction::write_field(
+            $record_account,
+            $signer,
+            ::core::mem::offset_of!($type, $field),
+            &value,
+        )
+    }};
+}
+
+/// Create a `RecordInstruction::SetAuthority` instruction
+pub fn set_authority(
+    record_account: &Address,
+    signer: &Address,
+    new_authority: &Address,
+) -> Instruction {
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*record_account, false),
+            AccountMeta::new_readonly(*signer, true),
+            AccountMeta::new_readonly(*new_authority, false),
+        ],
+        data: RecordInstruction::SetAuthority.pack(),
+    }
+}
+
+/// Create a `RecordInstruction::CloseAccount` instruction
+pub fn close_account(
+    record_account: &Address,
+    signer: &Address,
+    receiver: &Address,
+) -> Instruction {
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*record_account, false),
+            AccountMeta::new_readonly(*signer, true),
+            AccountMeta::new(*receiver, false),
+        ],
+        data: RecordInstruction::CloseAccount.pack(),
+    }
+}
+
+/// Create a `RecordInstruction::Reallocate` instruction
+pub fn reallocate(record_account: &Address, signer: &Address, data_length: u64) -> Instruction {
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*record_account, false),
+            AccountMeta::new_readonly(*signer, true),
+        ],
+        data: RecordInstruction::Reallocate { data_length }.pack(),
+    }
+}
+
+/// Create a `RecordInstruction::WriteIfEqual` instruction
+pub fn write_if_equal(
+    record_account: &Address,
+    signer: &Address,
+    check_offset: u64,
+    expected: &[u8],
+    write_offset: u64,
+    data: &[u8],
+) -> Instruction {
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*record_account, false),
+            AccountMeta::new_readonly(*signer, true),
+        ],
+        data: RecordInstruction::WriteIfEqual {
+            check_offset,
+            expected,
+            write_offset,
+            data,
+        }
+        .pack(),
+    }
+}
+
+/// Create a `RecordInstruction::WriteMultiple` instruction
+pub fn write_multiple(
+    record_accounts: &[Address],
+    signer: &Address,
+    writes: Vec<RecordWrite>,
+) -> Instruction {
+    let mut accounts = vec![AccountMeta::new_readonly(*signer, true)];
+    accounts.extend(
+        record_accounts
+            .iter()
+            .map(|record_account| AccountMeta::new(*record_account, false)),
+    );
+    Instruction {
+        program_id: id(),
+        accounts,
+        data: RecordInstruction::WriteMultiple { writes }.pack(),
+    }
+}
+
+/// Create a `RecordInstruction::Commit` instruction
+pub fn commit(
+    record_account: &Address,
+    buffer_account: &Address,
+    signer: &Address,
+    receiver: &Address,
+    expected_hash: Option<[u8; 32]>,
+) -> Instruction {
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*record_account, false),
+            AccountMeta::new(*buffer_account, false),
+            AccountMeta::new_readonly(*signer, true),
+            AccountMeta::new(*receiver, false),
+        ],
+        data: RecordInstruction::Commit { expected_hash }.pack(),
+    }
+}
+
+/// Create a `RecordInstruction::InitializeWithMode` instruction
+pub fn initialize_with_mode(
+    record_account: &Address,
+    authority: &Address,
+    mode: RecordMode,
+) -> Instruction {
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*record_account, false),
+            AccountMeta::new_readonly(*authority, false),
+        ],
+        data: RecordInstruction::InitializeWithMode { mode }.pack(),
+    }
+}
+
+/// Create a `RecordInstruction::InitializeRingBuffer` instruction
+pub fn initialize_ring_buffer(
+    record_account: &Address,
+    authority: &Address,
+    entry_size: u32,
+) -> Instruction {
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*record_account, false),
+            AccountMeta::new_readonly(*authority, false),
+        ],
+        data: RecordInstruction::InitializeRingBuffer { entry_size }.pack(),
+    }
+}
+
+/// Create a `RecordInstruction::PushEntry` instruction
+pub fn push_entry(record_account: &Address, signer: &Address, data: &[u8]) -> Instruction {
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*record_account, false),
+            AccountMeta::new_readonly(*signer, true),
+        ],
+        data: RecordInstruction::PushEntry { data }.pack(),
+    }
+}
+
+/// Create a `RecordInstruction::InitializeMerkleAccumulator` instruction
+pub fn initialize_merkle_accumulator(
+    record_account: &Address,
+    authority: &Address,
+    depth: u8,
+) -> Instruction {
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*record_account, false),
+            AccountMeta::new_readonly(*authority, false),
+        ],
+        data: RecordInstruction::InitializeMerkleAccumulator { depth }.pack(),
+    }
+}
+
+/// Create a `RecordInstruction::AppendLeaf` instruction
+pub fn append_leaf(record_account: &Address, signer: &Address, leaf: [u8; 32]) -> Instruction {
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*record_account, false),
+            AccountMeta::new_readonly(*signer, true),
+        ],
+        data: RecordInstruction::AppendLeaf { leaf }.pack(),
+    }
+}
+
+/// Create a `RecordInstruction::VerifyLeaf` instruction
+pub fn verify_leaf(
+    record_account: &Address,
+    leaf: [u8; 32],
+    index: u64,
+    proof: &[[u8; 32]],
+) -> Instruction {
+    Instruction {
+        program_id: id(),
+        accounts: vec![AccountMeta::new_readonly(*record_account, false)],
+        data: RecordInstruction::VerifyLeaf { leaf, index, proof }.pack(),
+    }
+}
+
+/// Create a `RecordInstruction::AppendShard` instruction
+pub fn append_shard(
+    manifest_account: &Address,
+    shard_account: &Address,
+    signer: &Address,
+    hash: [u8; 32],
+) -> Instruction {
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*manifest_account, false),
+            AccountMeta::new_readonly(*shard_account, false),
+            AccountMeta::new_readonly(*signer, true),
+        ],
+        data: RecordInstruction::AppendShard { hash }.pack(),
+    }
+}
+
+/// Create a `RecordInstruction::ReplaceShard` instruction
+pub fn replace_shard(
+    manifest_account: &Address,
+    shard_account: &Address,
+    signer: &Address,
+    index: u32,
+    hash: [u8; 32],
+) -> Instruction {
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*manifest_account, false),
+            AccountMeta::new_readonly(*shard_account, false),
+            AccountMeta::new_readonly(*signer, true),
+        ],
+        data: RecordInstruction::ReplaceShard { index, hash }.pack(),
+    }
+}
+
+/// Create a `RecordInstruction::InitializeContent` instruction, for the
+/// record at `get_content_address(&hash)`
+pub fn initialize_content(
+    payer: &Address,
+    authority: &Address,
+    hash: [u8; 32],
+    data_length: u64,
+) -> Instruction {
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*payer, true),
+            AccountMeta::new(get_content_address(&hash), false),
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new_readonly(solana_system_interface::program::id(), false),
+        ],
+        data: RecordInstruction::InitializeContent { hash, data_length }.pack(),
+    }
+}
+
+/// Create a `RecordInstruction::FinalizeContent` instruction
+pub fn finalize_content(record_account: &Address, signer: &Address, hash: [u8; 32]) -> Instruction {
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*record_account, false),
+            AccountMeta::new_readonly(*signer, true),
+        ],
+        data: RecordInstruction::FinalizeContent { hash }.pack(),
+    }
+}
+
+/// Create a `RecordInstruction::RegisterName` instruction, for the name
+/// account at `get_name_address(authority, name)`
+///
+/// Fails with `RecordError::InvalidName` if the name is empty or too long to
+/// derive the name account.
+pub fn register_name(
+    payer: &Address,
+    authority: &Address,
+    record_account: &Address,
+    name: &str,
+) -> Result<Instruction, ProgramError> {
+    Ok(Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*payer, true),
+            AccountMeta::new(get_name_address(authority, name)?, false),
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new_readonly(*record_account, false),
+            AccountMeta::new_readonly(solana_system_interface::program::id(), false),
+        ],
+        data: RecordInstruction::RegisterName { name }.pack(),
+    })
+}
+
+/// Create a `RecordInstruction::RepointName` instruction
+pub fn repoint_name(
+    name_account: &Address,
+    signer: &Address,
+    record_account: &Address,
+) -> Instruction {
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*name_account, false),
+            AccountMeta::new_readonly(*signer, true),
+            AccountMeta::new_readonly(*record_account, false),
+        ],
+        data: RecordInstruction::RepointName.pack(),
+    }
+}
+
+/// Create a `RecordInstruction::ReleaseName` instruction
+pub fn release_name(name_account: &Address, signer: &Address, receiver: &Address) -> Instruction {
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*name_account, false),
+            AccountMeta::new_readonly(*signer, true),
+            AccountMeta::new(*receiver, false),
+        ],
+        data: RecordInstruction::ReleaseName.pack(),
+    }
+}
+
+/// Create a `RecordInstruction::SetKey` instruction
+pub fn set_key(
+    record_account: &Address,
+    signer: &Address,
+    key: &[u8],
+    value: &[u8],
+) -> Instruction {
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*record_account, false),
+            AccountMeta::new_readonly(*signer, true),
+        ],
+        data: RecordInstruction::SetKey { key, value }.pack(),
+    }
+}
+
+/// Create a `RecordInstruction::DeleteKey` instruction
+pub fn delete_key(record_account: &Address, signer: &Address, key: &[u8]) -> Instruction {
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*record_account, false),
+            AccountMeta::new_readonly(*signer, true),
+        ],
+        data: RecordInstruction::DeleteKey { key }.pack(),
+    }
+}
+
+/// Create a `RecordInstruction::Migrate` instruction
+pub fn migrate(record_account: &Address, signer: &Address) -> Instruction {
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*record_account, false),
+            AccountMeta::new_readonly(*signer, true),
+        ],
+        data: RecordInstruction::Migrate.pack(),
+    }
+}
+
+/// Create a `RecordInstruction::InitializeAligned` instruction
+pub fn initialize_aligned(
+    record_account: &Address,
+    authority: &Address,
+    mode: RecordMode,
+    alignment: u8,
+) -> Instruction {
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*record_account, false),
+            AccountMeta::new_readonly(*authority, false),
+        ],
+        data: RecordInstruction::InitializeAligned { mode, alignment }.pack(),
+    }
+}
+
+/// Create a `RecordInstruction::InitializeChecked` instruction, signed by
+/// the authority
+pub fn initialize_checked(record_account: &Address, authority: &Address) -> Instruction {
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*record_account, false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+        data: RecordInstruction::InitializeChecked.pack(),
+    }
+}
+
+/// Create a `RecordInstruction::SetAuthorityChecked` instruction
+pub fn set_authority_checked(
+    record_account: &Address,
+    signer: &Address,
+    new_authority: &Address,
+) -> Instruction {
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*record_account, false),
+            AccountMeta::new_readonly(*signer, true),
+            AccountMeta::new_readonly(*new_authority, true),
+        ],
+        data: RecordInstruction::SetAuthorityChecked.pack(),
+    }
+}
+
+/// Create a `RecordInstruction::InitializeSponsored` instruction
+pub fn initialize_sponsored(
+    record_account: &Address,
+    authority: &Address,
+    beneficiary: &Address,
+    mode: RecordMode,
+) -> Instruction {
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*record_account, false),
+            AccountMeta::new_readonly(*authority, false),
+            AccountMeta::new_readonly(*beneficiary, false),
+        ],
+        data: RecordInstruction::InitializeSponsored { mode }.pack(),
+    }
+}
+
+/// Create a `RecordInstruction::ReallocateFunded` instruction
+pub fn reallocate_funded(
+    record_account: &Address,
+    signer: &Address,
+    payer: &Address,
+    data_length: u64,
+) -> Instruction {
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*record_account, false),
+            AccountMeta::new_readonly(*signer, true),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(solana_system_interface::program::id(), false),
+        ],
+        data: RecordInstruction::ReallocateFunded { data_length }.pack(),
+    }
+}
+
+/// Create a `RecordInstruction::SetExpiry` instruction
+pub fn set_expiry(record_account: &Address, signer: &Address, slot: u64) -> Instruction {
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*record_account, false),
+            AccountMeta::new_readonly(*signer, true),
+        ],
+        data: RecordInstruction::SetExpiry { slot }.pack(),
     }
 }
 
-/// Create a `RecordInstruction::Initialize` instruction
-pub fn initialize(record_account: &Address, authority: &Address) -> Instruction {
+/// Create a `RecordInstruction::CloseExpired` instruction
+pub fn close_expired(record_account: &Address, receiver: &Address) -> Instruction {
     Instruction {
         program_id: id(),
         accounts: vec![
             AccountMeta::new(*record_account, false),
-            AccountMeta::new_readonly(*authority, false),
+            AccountMeta::new(*receiver, false),
         ],
-        data: RecordInstruction::Initialize.pack(),
+        data: RecordInstruction::CloseExpired.pack(),
     }
 }
 
-/// Create a `RecordInstruction::Write` instruction
-pub fn write(record_account: &Address, signer: &Address, offset: u64, data: &[u8]) -> Instruction {
+/// Create a `RecordInstruction::SetWritePolicy` instruction
+pub fn set_write_policy(
+    record_account: &Address,
+    signer: &Address,
+    start_slot: u64,
+    end_slot: u64,
+    min_slot_gap: u64,
+) -> Instruction {
     Instruction {
         program_id: id(),
         accounts: vec![
             AccountMeta::new(*record_account, false),
             AccountMeta::new_readonly(*signer, true),
         ],
-        data: RecordInstruction::Write { offset, data }.pack(),
+        data: RecordInstruction::SetWritePolicy {
+            start_slot,
+            end_slot,
+            min_slot_gap,
+        }
+        .pack(),
     }
 }
 
-/// Create a `RecordInstruction::SetAuthority` instruction
-pub fn set_authority(
+/// Create a `RecordInstruction::RegisterSessionKey` instruction
+pub fn register_session_key(
     record_account: &Address,
     signer: &Address,
-    new_authority: &Address,
+    session_key: &Address,
+    expiry_slot: u64,
+    byte_budget: Option<u64>,
 ) -> Instruction {
     Instruction {
         program_id: id(),
         accounts: vec![
             AccountMeta::new(*record_account, false),
             AccountMeta::new_readonly(*signer, true),
-            AccountMeta::new_readonly(*new_authority, false),
+            AccountMeta::new_readonly(*session_key, false),
         ],
-        data: RecordInstruction::SetAuthority.pack(),
+        data: RecordInstruction::RegisterSessionKey {
+            expiry_slot,
+            byte_budget,
+        }
+        .pack(),
     }
 }
 
-/// Create a `RecordInstruction::CloseAccount` instruction
-pub fn close_account(
+/// Create a `RecordInstruction::RevokeSessionKey` instruction
+pub fn revoke_session_key(
     record_account: &Address,
     signer: &Address,
-    receiver: &Address,
+    session_key: &Address,
 ) -> Instruction {
     Instruction {
         program_id: id(),
         accounts: vec![
             AccountMeta::new(*record_account, false),
             AccountMeta::new_readonly(*signer, true),
-            AccountMeta::new(*receiver, false),
+            AccountMeta::new_readonly(*session_key, false),
         ],
-        data: RecordInstruction::CloseAccount.pack(),
+        data: RecordInstruction::RevokeSessionKey.pack(),
     }
 }
 
-/// Create a `RecordInstruction::Reallocate` instruction
-pub fn reallocate(record_account: &Address, signer: &Address, data_length: u64) -> Instruction {
+/// Create a `RecordInstruction::SetTokenGatedAuthority` instruction
+pub fn set_token_gated_authority(
+    record_account: &Address,
+    signer: &Address,
+    mint: &Address,
+) -> Instruction {
     Instruction {
         program_id: id(),
         accounts: vec![
             AccountMeta::new(*record_account, false),
             AccountMeta::new_readonly(*signer, true),
+            AccountMeta::new_readonly(*mint, false),
         ],
-        data: RecordInstruction::Reallocate { data_length }.pack(),
+        data: RecordInstruction::SetTokenGatedAuthority.pack(),
+    }
+}
+
+/// Create a `RecordInstruction::WriteSigned` instruction, to be preceded by
+/// an Ed25519 program instruction verifying the authority's signature over
+/// [`write_signed_message`]
+pub fn write_signed(record_account: &Address, offset: u64, nonce: u64, data: &[u8]) -> Instruction {
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*record_account, false),
+            AccountMeta::new_readonly(solana_instructions_sysvar::id(), false),
+        ],
+        data: RecordInstruction::WriteSigned {
+            offset,
+            nonce,
+            data,
+        }
+        .pack(),
     }
 }
 
+/// Prefix of every [`write_signed_message`], so that a signature over one
+/// cannot be replayed as a signature over another protocol's message
+pub const WRITE_SIGNED_DOMAIN: &[u8] = b"spl-record:write-signed:v1";
+
+/// Message the record authority signs off-chain for a
+/// `RecordInstruction::WriteSigned`: [`WRITE_SIGNED_DOMAIN`], the record
+/// address, then the offset and nonce as little-endian `u64`s, then the data
+pub fn write_signed_message(
+    record_account: &Address,
+    offset: u64,
+    nonce: u64,
+    data: &[u8],
+) -> Vec<u8> {
+    let mut message = Vec::with_capacity(
+        WRITE_SIGNED_DOMAIN
+            .len()
+            .saturating_add(48)
+            .saturating_add(data.len()),
+    );
+    message.extend_from_slice(WRITE_SIGNED_DOMAIN);
+    message.extend_from_slice(record_account.as_ref());
+    message.extend_from_slice(&offset.to_le_bytes());
+    message.extend_from_slice(&nonce.to_le_bytes());
+    message.extend_from_slice(data);
+    message
+}
+
+/// Pass the record's current rent beneficiary, as a signer, to a
+/// `RecordInstruction::ReallocateFunded` instruction paid by another account
+///
+/// Must be added before any gating token account.
+pub fn with_rent_beneficiary(mut instruction: Instruction, beneficiary: &Address) -> Instruction {
+    instruction
+        .accounts
+        .push(AccountMeta::new_readonly(*beneficiary, true));
+    instruction
+}
+
+/// Pass a payer to a `RecordInstruction::WriteSigned` instruction, funding
+/// the growth of the record for its nonce on the first signed write
+///
+/// Must be added after any gating token account.
+pub fn with_write_signed_payer(mut instruction: Instruction, payer: &Address) -> Instruction {
+    instruction.accounts.extend([
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(solana_system_interface::program::id(), false),
+    ]);
+    instruction
+}
+
+/// Pass the signer's token account to an instruction on a token-gated record
+pub fn with_gating_token_account(
+    mut instruction: Instruction,
+    token_account: &Address,
+) -> Instruction {
+    instruction
+        .accounts
+        .push(AccountMeta::new_readonly(*token_account, false));
+    instruction
+}
+
 #[cfg(test)]
 mod tests {
-    use {super::*, crate::state::tests::TEST_BYTES, solana_program_error::ProgramError};
+    use {
+        super::*,
+        crate::{
+            error::RecordError,
+            state::{tests::TEST_BYTES, NameRecord},
+        },
+        solana_program_error::ProgramError,
+    };
 
     #[test]
     fn serialize_initialize() {
@@ -255,8 +1770,458 @@ mod tests {
     }
 
     #[test]
-    fn deserialize_invalid_instruction() {
+    fn serialize_write_if_equal() {
+        let expected_bytes = &[7u8; 4];
+        let data = &TEST_BYTES;
+        let check_offset = 2u64;
+        let write_offset = 6u64;
+        let instruction = RecordInstruction::WriteIfEqual {
+            check_offset,
+            expected: expected_bytes,
+            write_offset,
+            data,
+        };
+        let mut expected = vec![5];
+        expected.extend_from_slice(&check_offset.to_le_bytes());
+        expected.extend_from_slice(&(expected_bytes.len() as u32).to_le_bytes());
+        expected.extend_from_slice(expected_bytes);
+        expected.extend_from_slice(&write_offset.to_le_bytes());
+        expected.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        expected.extend_from_slice(data);
+        assert_eq!(instruction.pack(), expected);
+        assert_eq!(RecordInstruction::unpack(&expected).unwrap(), instruction);
+    }
+
+    #[test]
+    fn serialize_write_multiple() {
+        let data = &TEST_BYTES;
+        let writes = vec![
+            RecordWrite {
+                record_index: 0,
+                offset: 0,
+                data,
+            },
+            RecordWrite {
+                record_index: 1,
+                offset: 4,
+                data: &data[..2],
+            },
+        ];
+        let instruction = RecordInstruction::WriteMultiple { writes };
+        let mut expected = vec![6, 2];
+        expected.push(0);
+        expected.extend_from_slice(&0u64.to_le_bytes());
+        expected.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        expected.extend_from_slice(data);
+        expected.push(1);
+        expected.extend_from_slice(&4u64.to_le_bytes());
+        expected.extend_from_slice(&2u32.to_le_bytes());
+        expected.extend_from_slice(&data[..2]);
+        assert_eq!(instruction.pack(), expected);
+        assert_eq!(RecordInstruction::unpack(&expected).unwrap(), instruction);
+    }
+
+    #[test]
+    fn serialize_commit() {
+        let instruction = RecordInstruction::Commit {
+            expected_hash: None,
+        };
+        let expected = vec![7, 0];
+        assert_eq!(instruction.pack(), expected);
+        assert_eq!(RecordInstruction::unpack(&expected).unwrap(), instruction);
+
+        let hash = [9u8; 32];
+        let instruction = RecordInstruction::Commit {
+            expected_hash: Some(hash),
+        };
+        let mut expected = vec![7, 1];
+        expected.extend_from_slice(&hash);
+        assert_eq!(instruction.pack(), expected);
+        assert_eq!(RecordInstruction::unpack(&expected).unwrap(), instruction);
+    }
+
+    #[test]
+    fn serialize_initialize_with_mode() {
+        let instruction = RecordInstruction::InitializeWithMode {
+            mode: RecordMode::AppendOnly,
+        };
+        let expected = vec![8, 1];
+        assert_eq!(instruction.pack(), expected);
+        assert_eq!(RecordInstruction::unpack(&expected).unwrap(), instruction);
+
+        let err = RecordInstruction::unpack(&[8, 255]).unwrap_err();
+        assert_eq!(err, ProgramError::InvalidInstructionData);
+    }
+
+    #[test]
+    fn serialize_initialize_ring_buffer() {
+        let entry_size = 32u32;
+        let instruction = RecordInstruction::InitializeRingBuffer { entry_size };
+        let mut expected = vec![9];
+        expected.extend_from_slice(&entry_size.to_le_bytes());
+        assert_eq!(instruction.pack(), expected);
+        assert_eq!(RecordInstruction::unpack(&expected).unwrap(), instruction);
+    }
+
+    #[test]
+    fn serialize_push_entry() {
+        let data = &TEST_BYTES;
+        let instruction = RecordInstruction::PushEntry { data };
+        let mut expected = vec![10];
+        expected.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        expected.extend_from_slice(data);
+        assert_eq!(instruction.pack(), expected);
+        assert_eq!(RecordInstruction::unpack(&expected).unwrap(), instruction);
+    }
+
+    #[test]
+    fn serialize_initialize_merkle_accumulator() {
+        let instruction = RecordInstruction::InitializeMerkleAccumulator { depth: 20 };
+        let expected = vec![11, 20];
+        assert_eq!(instruction.pack(), expected);
+        assert_eq!(RecordInstruction::unpack(&expected).unwrap(), instruction);
+    }
+
+    #[test]
+    fn serialize_append_leaf() {
+        let leaf = [7; 32];
+        let instruction = RecordInstruction::AppendLeaf { leaf };
         let mut expected = vec![12];
+        expected.extend_from_slice(&leaf);
+        assert_eq!(instruction.pack(), expected);
+        assert_eq!(RecordInstruction::unpack(&expected).unwrap(), instruction);
+    }
+
+    #[test]
+    fn serialize_verify_leaf() {
+        let leaf = [7; 32];
+        let index = 5u64;
+        let proof = [[1; 32], [2; 32]];
+        let instruction = RecordInstruction::VerifyLeaf {
+            leaf,
+            index,
+            proof: &proof,
+        };
+        let mut expected = vec![13];
+        expected.extend_from_slice(&leaf);
+        expected.extend_from_slice(&index.to_le_bytes());
+        expected.push(2);
+        expected.extend_from_slice(&[1; 32]);
+        expected.extend_from_slice(&[2; 32]);
+        assert_eq!(instruction.pack(), expected);
+        assert_eq!(RecordInstruction::unpack(&expected).unwrap(), instruction);
+
+        let err = RecordInstruction::unpack(&expected[..expected.len() - 1]).unwrap_err();
+        assert_eq!(err, ProgramError::InvalidInstructionData);
+    }
+
+    #[test]
+    fn serialize_append_shard() {
+        let hash = [3; 32];
+        let instruction = RecordInstruction::AppendShard { hash };
+        let mut expected = vec![14];
+        expected.extend_from_slice(&hash);
+        assert_eq!(instruction.pack(), expected);
+        assert_eq!(RecordInstruction::unpack(&expected).unwrap(), instruction);
+    }
+
+    #[test]
+    fn serialize_replace_shard() {
+        let index = 2u32;
+        let hash = [3; 32];
+        let instruction = RecordInstruction::ReplaceShard { index, hash };
+        let mut expected = vec![15];
+        expected.extend_from_slice(&index.to_le_bytes());
+        expected.extend_from_slice(&hash);
+        assert_eq!(instruction.pack(), expected);
+        assert_eq!(RecordInstruction::unpack(&expected).unwrap(), instruction);
+    }
+
+    #[test]
+    fn serialize_initialize_content() {
+        let hash = [3; 32];
+        let data_length = 1024u64;
+        let instruction = RecordInstruction::InitializeContent { hash, data_length };
+        let mut expected = vec![16];
+        expected.extend_from_slice(&hash);
+        expected.extend_from_slice(&data_length.to_le_bytes());
+        assert_eq!(instruction.pack(), expected);
+        assert_eq!(RecordInstruction::unpack(&expected).unwrap(), instruction);
+    }
+
+    #[test]
+    fn serialize_finalize_content() {
+        let hash = [3; 32];
+        let instruction = RecordInstruction::FinalizeContent { hash };
+        let mut expected = vec![17];
+        expected.extend_from_slice(&hash);
+        assert_eq!(instruction.pack(), expected);
+        assert_eq!(RecordInstruction::unpack(&expected).unwrap(), instruction);
+    }
+
+    #[test]
+    fn serialize_register_name() {
+        let name = "config";
+        let instruction = RecordInstruction::RegisterName { name };
+        let mut expected = vec![18];
+        expected.extend_from_slice(&(name.len() as u32).to_le_bytes());
+        expected.extend_from_slice(name.as_bytes());
+        assert_eq!(instruction.pack(), expected);
+        assert_eq!(RecordInstruction::unpack(&expected).unwrap(), instruction);
+
+        let err = RecordInstruction::unpack(&[18, 1, 0, 0, 0, 0xff]).unwrap_err();
+        assert_eq!(err, ProgramError::InvalidInstructionData);
+
+        let address = Address::new_unique();
+        let long_name = "n".repeat(NameRecord::MAX_NAME_LEN + 1);
+        assert_eq!(
+            register_name(&address, &address, &address, &long_name).unwrap_err(),
+            ProgramError::Custom(RecordError::InvalidName as u32)
+        );
+        assert_eq!(
+            crate::resolve_name(&address, &long_name, |_| None::<&[u8]>).unwrap_err(),
+            ProgramError::Custom(RecordError::InvalidName as u32)
+        );
+        assert_eq!(
+            register_name(&address, &address, &address, "").unwrap_err(),
+            ProgramError::Custom(RecordError::InvalidName as u32)
+        );
+    }
+
+    #[test]
+    fn serialize_repoint_name() {
+        let instruction = RecordInstruction::RepointName;
+        let expected = vec![19];
+        assert_eq!(instruction.pack(), expected);
+        assert_eq!(RecordInstruction::unpack(&expected).unwrap(), instruction);
+    }
+
+    #[test]
+    fn serialize_release_name() {
+        let instruction = RecordInstruction::ReleaseName;
+        let expected = vec![20];
+        assert_eq!(instruction.pack(), expected);
+        assert_eq!(RecordInstruction::unpack(&expected).unwrap(), instruction);
+    }
+
+    #[test]
+    fn serialize_set_key() {
+        let key = b"key";
+        let value = &TEST_BYTES;
+        let instruction = RecordInstruction::SetKey { key, value };
+        let mut expected = vec![21];
+        expected.extend_from_slice(&(key.len() as u32).to_le_bytes());
+        expected.extend_from_slice(key);
+        expected.extend_from_slice(&(value.len() as u32).to_le_bytes());
+        expected.extend_from_slice(value);
+        assert_eq!(instruction.pack(), expected);
+        assert_eq!(RecordInstruction::unpack(&expected).unwrap(), instruction);
+    }
+
+    #[test]
+    fn serialize_delete_key() {
+        let key = b"key";
+        let instruction = RecordInstruction::DeleteKey { key };
+        let mut expected = vec![22];
+        expected.extend_from_slice(&(key.len() as u32).to_le_bytes());
+        expected.extend_from_slice(key);
+        assert_eq!(instruction.pack(), expected);
+        assert_eq!(RecordInstruction::unpack(&expected).unwrap(), instruction);
+    }
+
+    #[test]
+    fn write_field_offsets() {
+        #[repr(C)]
+        #[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+        struct Counter {
+            count: u64,
+            limit: u32,
+            step: u32,
+        }
+
+        let record = Address::new_unique();
+        let authority = Address::new_unique();
+        let instruction = crate::write_field!(&record, &authority, Counter, step, 5);
+        assert_eq!(
+            RecordInstruction::unpack(&instruction.data).unwrap(),
+            RecordInstruction::Write {
+                offset: 12,
+                data: &5u32.to_le_bytes(),
+            }
+        );
+        assert_eq!(instruction.accounts[0].pubkey, record);
+
+        let instruction = write_typed(
+            &record,
+            &authority,
+            &Counter {
+                count: 1,
+                limit: 2,
+                step: 3,
+            },
+        );
+        let RecordInstruction::Write { offset, data } =
+            RecordInstruction::unpack(&instruction.data).unwrap()
+        else {
+            panic!("expected a write instruction");
+        };
+        assert_eq!(offset, 0);
+        assert_eq!(data.len(), 16);
+    }
+
+    #[test]
+    fn serialize_migrate() {
+        let instruction = RecordInstruction::Migrate;
+        let expected = vec![23];
+        assert_eq!(instruction.pack(), expected);
+        assert_eq!(RecordInstruction::unpack(&expected).unwrap(), instruction);
+    }
+
+    #[test]
+    fn serialize_initialize_aligned() {
+        let instruction = RecordInstruction::InitializeAligned {
+            mode: RecordMode::Standard,
+            alignment: 8,
+        };
+        let expected = vec![24, 0, 8];
+        assert_eq!(instruction.pack(), expected);
+        assert_eq!(RecordInstruction::unpack(&expected).unwrap(), instruction);
+
+        let err = RecordInstruction::unpack(&[24, 0]).unwrap_err();
+        assert_eq!(err, ProgramError::InvalidInstructionData);
+    }
+
+    #[test]
+    fn serialize_initialize_checked() {
+        let instruction = RecordInstruction::InitializeChecked;
+        let expected = vec![25];
+        assert_eq!(instruction.pack(), expected);
+        assert_eq!(RecordInstruction::unpack(&expected).unwrap(), instruction);
+    }
+
+    #[test]
+    fn serialize_set_authority_checked() {
+        let instruction = RecordInstruction::SetAuthorityChecked;
+        let expected = vec![26];
+        assert_eq!(instruction.pack(), expected);
+        assert_eq!(RecordInstruction::unpack(&expected).unwrap(), instruction);
+    }
+
+    #[test]
+    fn serialize_sponsored_records() {
+        let instruction = RecordInstruction::InitializeSponsored {
+            mode: RecordMode::KeyValue,
+        };
+        let expected = vec![27, RecordMode::KeyValue as u8];
+        assert_eq!(instruction.pack(), expected);
+        assert_eq!(RecordInstruction::unpack(&expected).unwrap(), instruction);
+
+        let data_length = 16u64;
+        let instruction = RecordInstruction::ReallocateFunded { data_length };
+        let mut expected = vec![28];
+        expected.extend_from_slice(&data_length.to_le_bytes());
+        assert_eq!(instruction.pack(), expected);
+        assert_eq!(RecordInstruction::unpack(&expected).unwrap(), instruction);
+    }
+
+    #[test]
+    fn serialize_expiry() {
+        let slot = 1_000u64;
+        let instruction = RecordInstruction::SetExpiry { slot };
+        let mut expected = vec![29];
+        expected.extend_from_slice(&slot.to_le_bytes());
+        assert_eq!(instruction.pack(), expected);
+        assert_eq!(RecordInstruction::unpack(&expected).unwrap(), instruction);
+
+        let instruction = RecordInstruction::CloseExpired;
+        let expected = vec![30];
+        assert_eq!(instruction.pack(), expected);
+        assert_eq!(RecordInstruction::unpack(&expected).unwrap(), instruction);
+    }
+
+    #[test]
+    fn serialize_set_write_policy() {
+        let instruction = RecordInstruction::SetWritePolicy {
+            start_slot: 10,
+            end_slot: 1_000,
+            min_slot_gap: 5,
+        };
+        let mut expected = vec![31];
+        expected.extend_from_slice(&10u64.to_le_bytes());
+        expected.extend_from_slice(&1_000u64.to_le_bytes());
+        expected.extend_from_slice(&5u64.to_le_bytes());
+        assert_eq!(instruction.pack(), expected);
+        assert_eq!(RecordInstruction::unpack(&expected).unwrap(), instruction);
+    }
+
+    #[test]
+    fn serialize_session_keys() {
+        let instruction = RecordInstruction::RegisterSessionKey {
+            expiry_slot: 1_000,
+            byte_budget: None,
+        };
+        let mut expected = vec![32];
+        expected.extend_from_slice(&1_000u64.to_le_bytes());
+        expected.push(0);
+        assert_eq!(instruction.pack(), expected);
+        assert_eq!(RecordInstruction::unpack(&expected).unwrap(), instruction);
+
+        let instruction = RecordInstruction::RegisterSessionKey {
+            expiry_slot: 1_000,
+            byte_budget: Some(64),
+        };
+        let mut expected = vec![32];
+        expected.extend_from_slice(&1_000u64.to_le_bytes());
+        expected.push(1);
+        expected.extend_from_slice(&64u64.to_le_bytes());
+        assert_eq!(instruction.pack(), expected);
+        assert_eq!(RecordInstruction::unpack(&expected).unwrap(), instruction);
+
+        let instruction = RecordInstruction::RevokeSessionKey;
+        let expected = vec![33];
+        assert_eq!(instruction.pack(), expected);
+        assert_eq!(RecordInstruction::unpack(&expected).unwrap(), instruction);
+    }
+
+    #[test]
+    fn serialize_set_token_gated_authority() {
+        let instruction = RecordInstruction::SetTokenGatedAuthority;
+        let expected = vec![34];
+        assert_eq!(instruction.pack(), expected);
+        assert_eq!(RecordInstruction::unpack(&expected).unwrap(), instruction);
+    }
+
+    #[test]
+    fn serialize_write_signed() {
+        let data = &TEST_BYTES;
+        let instruction = RecordInstruction::WriteSigned {
+            offset: 2,
+            nonce: 7,
+            data,
+        };
+        let mut expected = vec![35];
+        expected.extend_from_slice(&2u64.to_le_bytes());
+        expected.extend_from_slice(&7u64.to_le_bytes());
+        expected.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        expected.extend_from_slice(data);
+        assert_eq!(instruction.pack(), expected);
+        assert_eq!(RecordInstruction::unpack(&expected).unwrap(), instruction);
+    }
+
+    #[test]
+    fn deserialize_truncated_write() {
+        let data = &TEST_BYTES;
+        let mut expected = vec![1];
+        expected.extend_from_slice(&0u64.to_le_bytes());
+        expected.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        expected.extend_from_slice(&data[..4]);
+        let err: ProgramError = RecordInstruction::unpack(&expected).unwrap_err();
+        assert_eq!(err, ProgramError::InvalidInstructionData);
+    }
+
+    #[test]
+    fn deserialize_invalid_instruction() {
+        let mut expected = vec![u8::MAX];
         expected.extend_from_slice(&TEST_BYTES);
         let err: ProgramError = RecordInstruction::unpack(&expected).unwrap_err();
         assert_eq!(err, ProgramError::InvalidInstructionData);
@@ -3,7 +3,9 @@
 
 mod entrypoint;
 pub mod error;
+pub mod extension;
 pub mod instruction;
+pub mod merkle;
 pub mod processor;
 pub mod state;
 
@@ -15,3 +17,69 @@ pub use {
 };
 
 solana_address::declare_id!("recr1L3PCGKLbckBqMNcJhuuyU1zgo8nBhfLVsJNwr5");
+
+use {
+    error::RecordError, solana_address::Address, solana_program_error::ProgramError,
+    state::NameRecord,
+};
+
+/// Seed prefix of content-addressed record addresses
+pub const CONTENT_SEED: &[u8] = b"content";
+
+/// Derives the address of the content-addressed record holding a payload
+/// with the given SHA-256 hash
+pub fn get_content_address(hash: &[u8; 32]) -> Address {
+    get_content_address_and_bump_seed(hash, &id()).0
+}
+
+pub(crate) fn get_content_address_and_bump_seed(
+    hash: &[u8; 32],
+    program_id: &Address,
+) -> (Address, u8) {
+    Address::find_program_address(&[CONTENT_SEED, hash], program_id)
+}
+
+/// Seed prefix of record name addresses
+pub const NAME_SEED: &[u8] = b"name";
+
+/// Derives the address of the account registering `name` for `authority`
+///
+/// Names are 1 to `NameRecord::MAX_NAME_LEN` bytes long, other names have no
+/// valid address and fail with `RecordError::InvalidName`.
+pub fn get_name_address(authority: &Address, name: &str) -> Result<Address, ProgramError> {
+    if name.is_empty() || name.len() > NameRecord::MAX_NAME_LEN {
+        return Err(RecordError::InvalidName.into());
+    }
+    Ok(get_name_address_and_bump_seed(authority, name, &id()).0)
+}
+
+pub(crate) fn get_name_address_and_bump_seed(
+    authority: &Address,
+    name: &str,
+    program_id: &Address,
+) -> (Address, u8) {
+    Address::find_program_address(
+        &[NAME_SEED, authority.as_ref(), name.as_bytes()],
+        program_id,
+    )
+}
+
+/// Resolves the record address `name` points to for `authority`, using
+/// `get_account_data` to fetch the data of the name account
+pub fn resolve_name<F, D>(
+    authority: &Address,
+    name: &str,
+    get_account_data: F,
+) -> Result<Address, ProgramError>
+where
+    F: FnOnce(&Address) -> Option<D>,
+    D: AsRef<[u8]>,
+{
+    let account_data = get_account_data(&get_name_address(authority, name)?)
+        .ok_or(ProgramError::UninitializedAccount)?;
+    let name_record = NameRecord::unpack(account_data.as_ref())?;
+    if name_record.authority != *authority {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    Ok(name_record.record)
+}
@@ -0,0 +1,201 @@
+//! Merkle accumulator hashing, shared by the program and off-chain clients
+
+use {
+    crate::error::RecordError, solana_program_error::ProgramError, solana_sha256_hasher::hashv,
+    std::iter::successors,
+};
+
+/// Maximum depth of a Merkle accumulator record
+pub const MAX_DEPTH: u8 = 32;
+
+/// Length of a node in the tree
+pub const NODE_LEN: usize = 32;
+
+/// A node in the tree
+pub type Node = [u8; NODE_LEN];
+
+/// Hash arbitrary data into a leaf node
+pub fn hash_leaf(data: &[u8]) -> Node {
+    hashv(&[&[0], data]).to_bytes()
+}
+
+/// Hash two child nodes into their parent node
+pub fn hash_nodes(left: &Node, right: &Node) -> Node {
+    hashv(&[&[1], left, right]).to_bytes()
+}
+
+/// Iterate over the roots of empty subtrees, starting from an empty leaf
+fn zero_nodes() -> impl Iterator<Item = Node> {
+    successors(Some([0; NODE_LEN]), |node| Some(hash_nodes(node, node)))
+}
+
+/// Append a leaf to the frontier of an accumulator currently holding
+/// `leaf_count` leaves, returning the new root
+///
+/// The frontier holds one node per level of the tree, keeping the left
+/// siblings needed to compute the root as further leaves are appended.
+pub fn append_leaf(
+    frontier: &mut [Node],
+    leaf_count: u64,
+    leaf: &Node,
+) -> Result<Node, ProgramError> {
+    if leaf_count >= capacity(frontier.len()) {
+        return Err(RecordError::MerkleTreeFull.into());
+    }
+    let mut node = *leaf;
+    for (level, (sibling, zero)) in frontier.iter_mut().zip(zero_nodes()).enumerate() {
+        node = if is_right_child(leaf_count, level) {
+            hash_nodes(sibling, &node)
+        } else {
+            *sibling = node;
+            hash_nodes(&node, &zero)
+        };
+    }
+    Ok(node)
+}
+
+/// Number of leaves a tree of the given depth can hold
+pub fn capacity(depth: usize) -> u64 {
+    u32::try_from(depth)
+        .ok()
+        .and_then(|depth| 1u64.checked_shl(depth))
+        .unwrap_or(u64::MAX)
+}
+
+/// Root of a tree of the given depth without any leaves
+pub fn empty_root(depth: u8) -> Node {
+    zero_nodes().nth(depth as usize).unwrap()
+}
+
+/// Whether the ancestor at `level` of the leaf at `index` is a right child
+fn is_right_child(index: u64, level: usize) -> bool {
+    u32::try_from(level)
+        .ok()
+        .and_then(|level| index.checked_shr(level))
+        .is_some_and(|bits| bits & 1 == 1)
+}
+
+/// Compute the root implied by an inclusion proof for the leaf at `index`
+pub fn proof_root(leaf: &Node, index: u64, proof: &[Node]) -> Node {
+    proof
+        .iter()
+        .enumerate()
+        .fold(*leaf, |node, (level, sibling)| {
+            if is_right_child(index, level) {
+                hash_nodes(sibling, &node)
+            } else {
+                hash_nodes(&node, sibling)
+            }
+        })
+}
+
+/// Off-chain Merkle tree, mirroring the leaves appended to an accumulator
+/// record in order to build inclusion proofs
+#[derive(Clone, Debug, PartialEq)]
+pub struct MerkleTree {
+    depth: u8,
+    leaves: Vec<Node>,
+}
+
+impl MerkleTree {
+    /// Create an empty tree of the given depth
+    pub fn new(depth: u8) -> Self {
+        Self {
+            depth,
+            leaves: vec![],
+        }
+    }
+
+    /// Append a leaf to the tree
+    pub fn push(&mut self, leaf: Node) {
+        self.leaves.push(leaf);
+    }
+
+    /// Leaves appended to the tree
+    pub fn leaves(&self) -> &[Node] {
+        &self.leaves
+    }
+
+    /// Nodes of each level of the tree, from the leaves up to the root
+    fn levels(&self) -> Vec<Vec<Node>> {
+        let mut levels = vec![self.leaves.clone()];
+        for zero in zero_nodes().take(self.depth as usize) {
+            let level = levels.last().unwrap();
+            let parents = level
+                .chunks(2)
+                .map(|pair| hash_nodes(&pair[0], pair.get(1).unwrap_or(&zero)))
+                .collect();
+            levels.push(parents);
+        }
+        levels
+    }
+
+    /// Root of the tree
+    pub fn root(&self) -> Node {
+        self.levels()
+            .last()
+            .and_then(|level| level.first().copied())
+            .unwrap_or_else(|| empty_root(self.depth))
+    }
+
+    /// Inclusion proof for the leaf at `index`, if present
+    pub fn proof(&self, index: u64) -> Option<Vec<Node>> {
+        let index = usize::try_from(index).ok()?;
+        if index >= self.leaves.len() {
+            return None;
+        }
+        let levels = self.levels();
+        Some(
+            levels
+                .iter()
+                .zip(zero_nodes())
+                .take(self.depth as usize)
+                .enumerate()
+                .map(|(level, (nodes, zero))| {
+                    let sibling = index.checked_shr(level as u32).unwrap_or(0) ^ 1;
+                    nodes.get(sibling).copied().unwrap_or(zero)
+                })
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frontier_matches_tree() {
+        let depth = 4;
+        let mut frontier = vec![[0; NODE_LEN]; depth as usize];
+        let mut tree = MerkleTree::new(depth);
+        assert_eq!(empty_root(depth), tree.root());
+
+        for i in 0..(1u64 << depth) {
+            let leaf = hash_leaf(&i.to_le_bytes());
+            let root = append_leaf(&mut frontier, i, &leaf).unwrap();
+            tree.push(leaf);
+            assert_eq!(root, tree.root());
+        }
+        assert_eq!(
+            append_leaf(&mut frontier, 1 << depth, &[0; NODE_LEN]),
+            Err(RecordError::MerkleTreeFull.into())
+        );
+    }
+
+    #[test]
+    fn proofs_verify_against_root() {
+        let mut tree = MerkleTree::new(3);
+        for i in 0..5u8 {
+            tree.push(hash_leaf(&[i]));
+        }
+        let root = tree.root();
+        for (index, leaf) in tree.leaves().iter().enumerate() {
+            let proof = tree.proof(index as u64).unwrap();
+            assert_eq!(proof.len(), 3);
+            assert_eq!(proof_root(leaf, index as u64, &proof), root);
+            assert_ne!(proof_root(leaf, index as u64 ^ 1, &proof), root);
+        }
+        assert!(tree.proof(5).is_none());
+    }
+}
@@ -1,12 +1,36 @@
 //! Program state processor
 
 use {
-    crate::{error::RecordError, instruction::RecordInstruction, state::RecordData},
+    crate::{
+        error::RecordError,
+        extension::{
+            self, Expiry, ExtensionType, RentBeneficiary, SessionKey, SessionKeys, TokenGate,
+            WriteNonce, WritePolicy,
+        },
+        get_content_address_and_bump_seed, get_name_address_and_bump_seed,
+        instruction::{write_signed_message, RecordInstruction},
+        merkle::{self, Node, NODE_LEN},
+        state::{
+            KeyValueHeader, KeyValueMap, Manifest, ManifestHeader, MerkleHeader, NameRecord,
+            RecordAccount, RecordData, RecordDataV2, RecordHeader, RecordMode, RingBufferHeader,
+            ShardEntry,
+        },
+        CONTENT_SEED, NAME_SEED,
+    },
     solana_account_info::{next_account_info, AccountInfo},
     solana_address::Address,
+    solana_cpi::{invoke, invoke_signed},
+    solana_ed25519_program::{
+        Ed25519SignatureOffsets, DATA_START, PUBKEY_SERIALIZED_SIZE, SIGNATURE_OFFSETS_START,
+    },
+    solana_instructions_sysvar::get_instruction_relative,
     solana_msg::msg,
     solana_program_error::{ProgramError, ProgramResult},
-    solana_program_pack::IsInitialized,
+    solana_sha256_hasher::hash,
+    solana_system_interface::instruction as system_instruction,
+    solana_sysvar::{clock::Clock, rent::Rent, Sysvar},
+    spl_generic_token::token::{GenericTokenAccount, GenericTokenMint},
+    std::ops::{Deref, DerefMut},
 };
 
 fn check_authority(authority_info: &AccountInfo, expected_authority: &Address) -> ProgramResult {
@@ -21,9 +45,735 @@ fn check_authority(authority_info: &AccountInfo, expected_authority: &Address) -
     Ok(())
 }
 
+/// Checks the signer authorizing a change to a record
+///
+/// The authority of a token-gated record is a mint, so the signer must
+/// instead own a token account of the mint holding at least one token, passed
+/// as the first of `gate_accounts`.
+fn check_record_authority<D: Deref<Target = [u8]>>(
+    record: &RecordAccount<D>,
+    authority_info: &AccountInfo,
+    gate_accounts: &[AccountInfo],
+) -> ProgramResult {
+    let Some(token_program) = gating_token_program(record)? else {
+        return check_authority(authority_info, record.authority());
+    };
+    if !authority_info.is_signer {
+        msg!("Token holder signature missing");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    check_token_holder(
+        record.authority(),
+        &token_program,
+        authority_info.key,
+        gate_accounts,
+    )
+}
+
+/// Checks that `holder` owns a token account of `mint` holding at least one
+/// token, passed as the first of `gate_accounts`
+fn check_token_holder(
+    mint: &Address,
+    token_program: &Address,
+    holder: &Address,
+    gate_accounts: &[AccountInfo],
+) -> ProgramResult {
+    let token_account_info = gate_accounts.first().ok_or_else(|| {
+        msg!("Token-gated records require the signer's token account");
+        ProgramError::NotEnoughAccountKeys
+    })?;
+    if token_account_info.owner != token_program {
+        msg!("Token account is not owned by the mint's token program");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let token_account_data = token_account_info.try_borrow_data()?;
+    let (token_mint, owner, amount) = if *token_program == spl_generic_token::token::id() {
+        unpack_token_account::<spl_generic_token::token::Account>(&token_account_data)
+    } else {
+        unpack_token_account::<spl_generic_token::token_2022::Account>(&token_account_data)
+    }
+    .ok_or(ProgramError::InvalidAccountData)?;
+    if token_mint != mint || owner != holder || amount == 0 {
+        msg!("Signer must own a token account holding the record's mint");
+        return Err(RecordError::TokenNotHeld.into());
+    }
+    Ok(())
+}
+
+/// Token program of the mint gating a record, if its authority is token-gated
+fn gating_token_program<D: Deref<Target = [u8]>>(
+    record: &RecordAccount<D>,
+) -> Result<Option<Address>, ProgramError> {
+    match extension::get_extension::<TokenGate>(record.extensions()) {
+        Ok(gate) if gate.is_enabled() => Ok(Some(gate.token_program)),
+        Ok(_) => Ok(None),
+        Err(error) if error == RecordError::ExtensionNotFound.into() => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+/// Unpacks the mint, owner and amount of an initialized token account
+fn unpack_token_account<A: GenericTokenAccount>(
+    account_data: &[u8],
+) -> Option<(&Address, &Address, u64)> {
+    Some((
+        A::unpack_account_mint(account_data)?,
+        A::unpack_account_owner(account_data)?,
+        A::unpack_account_amount(account_data)?,
+    ))
+}
+
+/// Checks that the instruction before the current one is an Ed25519 program
+/// instruction verifying a signature over `message`, returning the signer
+fn check_write_signature(
+    instructions_sysvar_info: &AccountInfo,
+    message: &[u8],
+) -> Result<Address, ProgramError> {
+    let signature_instruction = match get_instruction_relative(-1, instructions_sysvar_info) {
+        Ok(instruction) => instruction,
+        Err(ProgramError::InvalidArgument) => {
+            msg!("Signed writes must follow an Ed25519 program instruction");
+            return Err(RecordError::InvalidWriteSignature.into());
+        }
+        Err(error) => return Err(error),
+    };
+    if signature_instruction.program_id != solana_sdk_ids::ed25519_program::id() {
+        msg!("Signed writes must follow an Ed25519 program instruction");
+        return Err(RecordError::InvalidWriteSignature.into());
+    }
+
+    let data = &signature_instruction.data;
+    if data.first() != Some(&1) {
+        msg!("Ed25519 instruction must verify exactly one signature");
+        return Err(RecordError::InvalidWriteSignature.into());
+    }
+    let offsets = data
+        .get(SIGNATURE_OFFSETS_START..DATA_START)
+        .map(bytemuck::pod_read_unaligned::<Ed25519SignatureOffsets>)
+        .ok_or(RecordError::InvalidWriteSignature)?;
+    // the precompile verifies whatever the offsets point to, which must be
+    // the same bytes checked below
+    if [
+        offsets.signature_instruction_index,
+        offsets.public_key_instruction_index,
+        offsets.message_instruction_index,
+    ] != [u16::MAX; 3]
+    {
+        msg!("Ed25519 instruction must hold its own signature, public key and message");
+        return Err(RecordError::InvalidWriteSignature.into());
+    }
+    let public_key_start = offsets.public_key_offset as usize;
+    let signer = data
+        .get(public_key_start..public_key_start.saturating_add(PUBKEY_SERIALIZED_SIZE))
+        .ok_or(RecordError::InvalidWriteSignature)?;
+    let message_start = offsets.message_data_offset as usize;
+    let signed_message = data
+        .get(message_start..message_start.saturating_add(offsets.message_data_size as usize))
+        .ok_or(RecordError::InvalidWriteSignature)?;
+    if signed_message != message {
+        msg!("Signed message does not match the write");
+        return Err(RecordError::InvalidWriteSignature.into());
+    }
+    Address::try_from(signer).map_err(|_| RecordError::InvalidWriteSignature.into())
+}
+
+/// Checks the signer of a `Write`, which may be the record authority or one
+/// of its session keys, charging `length` bytes to a session key's budget
+fn check_write_signer<D: DerefMut<Target = [u8]>>(
+    record: &mut RecordAccount<D>,
+    signer_info: &AccountInfo,
+    gate_accounts: &[AccountInfo],
+    length: usize,
+) -> ProgramResult {
+    if record.authority() == signer_info.key || !signer_info.is_signer {
+        return check_record_authority(record, signer_info, gate_accounts);
+    }
+    let token_program = gating_token_program(record)?;
+    let mint = *record.authority();
+    let sessions = match extension::get_extension_mut::<SessionKeys>(record.extensions_mut()) {
+        Ok(sessions) => sessions,
+        Err(error) if error == RecordError::ExtensionNotFound.into() => {
+            return check_record_authority(record, signer_info, gate_accounts);
+        }
+        Err(error) => return Err(error),
+    };
+    let Some(session) = sessions.find_mut(signer_info.key) else {
+        return check_record_authority(record, signer_info, gate_accounts);
+    };
+    // keys registered by a token holder lapse once the token changes hands
+    if let Some(token_program) = token_program {
+        check_token_holder(&mint, &token_program, &session.holder, gate_accounts)?;
+    }
+    let slot = Clock::get()?.slot;
+    session.authorize_write(slot, length).map_err(|error| {
+        match error {
+            RecordError::SessionKeyExpired => msg!(
+                "Session key expired at slot {}",
+                u64::from_le_bytes(session.expiry_slot)
+            ),
+            _ => msg!(
+                "Session key can only write {} more bytes",
+                u64::from_le_bytes(session.remaining_bytes)
+            ),
+        }
+        error.into()
+    })
+}
+
+fn check_owner(account_info: &AccountInfo, program_id: &Address) -> ProgramResult {
+    if account_info.owner != program_id {
+        msg!(
+            "Account {} is not owned by the record program",
+            account_info.key
+        );
+        return Err(RecordError::IncorrectOwner.into());
+    }
+    Ok(())
+}
+
+fn check_writable(account_info: &AccountInfo) -> ProgramResult {
+    if !account_info.is_writable {
+        msg!("Account {} must be writable", account_info.key);
+        return Err(RecordError::AccountNotWritable.into());
+    }
+    Ok(())
+}
+
+fn check_distinct(account_info: &AccountInfo, other_info: &AccountInfo) -> ProgramResult {
+    if account_info.key == other_info.key {
+        msg!(
+            "Account {} provided for more than one role",
+            account_info.key
+        );
+        return Err(RecordError::DuplicateAccount.into());
+    }
+    Ok(())
+}
+
+/// Checks that writing `length` bytes at payload `offset` is allowed by the
+/// record's mode, raising the high-water mark of append-only records
+fn check_write_mode<D: DerefMut<Target = [u8]>>(
+    record: &mut RecordAccount<D>,
+    offset: u64,
+    length: usize,
+) -> ProgramResult {
+    match record.mode()? {
+        RecordMode::Standard => Ok(()),
+        RecordMode::AppendOnly => {
+            let account_data = record
+                .header_v2_mut()
+                .ok_or(ProgramError::InvalidAccountData)?;
+            if offset < account_data.high_water_mark() {
+                msg!("Append-only record data cannot be rewritten");
+                return Err(RecordError::AppendOnlyViolation.into());
+            }
+            account_data.set_high_water_mark(offset.saturating_add(length as u64));
+            Ok(())
+        }
+        RecordMode::RingBuffer => {
+            msg!("Ring buffer records can only be modified by pushing entries");
+            Err(RecordError::InvalidRecordMode.into())
+        }
+        RecordMode::MerkleAccumulator => {
+            msg!("Merkle accumulator records can only be modified by appending leaves");
+            Err(RecordError::InvalidRecordMode.into())
+        }
+        RecordMode::Manifest => {
+            msg!("Manifest records can only be modified by appending or replacing shards");
+            Err(RecordError::InvalidRecordMode.into())
+        }
+        RecordMode::ContentPending => Ok(()),
+        RecordMode::Content => {
+            msg!("Content-addressed records cannot be modified once finalized");
+            Err(RecordError::RecordFrozen.into())
+        }
+        RecordMode::KeyValue => {
+            msg!("Key-value records can only be modified by setting or deleting keys");
+            Err(RecordError::InvalidRecordMode.into())
+        }
+    }
+}
+
+/// Checks that the header region of an account is untouched, so that no
+/// initialized account of the program, records or names alike, can be
+/// initialized again
+fn check_uninitialized(header: &[u8]) -> ProgramResult {
+    if header.iter().any(|byte| *byte != 0) {
+        msg!("Record account already initialized");
+        return Err(RecordError::AlreadyInitialized.into());
+    }
+    Ok(())
+}
+
+/// Initializes the header of an uninitialized record
+fn initialize_record(raw_data: &mut [u8], authority: &Address) -> ProgramResult {
+    if raw_data.len() < RecordData::WRITABLE_START_INDEX {
+        msg!("Record account too small to hold the header");
+        return Err(RecordError::HeaderTooSmall.into());
+    }
+
+    check_uninitialized(&raw_data[..RecordData::WRITABLE_START_INDEX])?;
+    let account_data = bytemuck::try_from_bytes_mut::<RecordData>(
+        &mut raw_data[..RecordData::WRITABLE_START_INDEX],
+    )
+    .map_err(|_| ProgramError::InvalidArgument)?;
+
+    account_data.authority = *authority;
+    account_data.version = RecordData::CURRENT_VERSION;
+    Ok(())
+}
+
+/// Initializes the extended header of an uninitialized record, followed by an
+/// extension region of free space, returning the start of its payload
+fn initialize_record_v2(
+    raw_data: &mut [u8],
+    authority: &Address,
+    mode: RecordMode,
+    extensions_length: usize,
+    alignment: u8,
+) -> Result<usize, ProgramError> {
+    if raw_data.len() < RecordDataV2::LEN {
+        msg!("Record account too small to hold the extended header");
+        return Err(RecordError::HeaderTooSmall.into());
+    }
+
+    check_uninitialized(&raw_data[..RecordDataV2::LEN])?;
+    let account_data =
+        bytemuck::try_from_bytes_mut::<RecordDataV2>(&mut raw_data[..RecordDataV2::LEN])
+            .map_err(|_| ProgramError::InvalidArgument)?;
+    account_data.authority = *authority;
+    account_data.version = RecordDataV2::VERSION;
+    account_data.mode = mode as u8;
+    account_data.set_high_water_mark(0);
+    account_data.extensions_length = u32::try_from(extensions_length)
+        .map_err(|_| ProgramError::InvalidArgument)?
+        .to_le_bytes();
+    account_data.alignment = alignment;
+    let writable_start_index = account_data.writable_start_index();
+    raw_data
+        .get_mut(RecordDataV2::LEN..writable_start_index)
+        .ok_or(ProgramError::AccountDataTooSmall)?
+        .fill(0);
+    Ok(writable_start_index)
+}
+
+/// Initializes a record with one of the modes accepted by `InitializeWithMode`,
+/// with an extension region of the given length holding the rent beneficiary
+/// extension, if any, followed by free space, and its payload offset kept at
+/// `alignment`
+fn initialize_with_mode(
+    program_id: &Address,
+    data_info: &AccountInfo,
+    authority_info: &AccountInfo,
+    mode: RecordMode,
+    extensions_length: usize,
+    alignment: u8,
+    rent_beneficiary: Option<&Address>,
+) -> ProgramResult {
+    match mode {
+        RecordMode::RingBuffer => {
+            msg!("Ring buffer records must be initialized with InitializeRingBuffer");
+            return Err(RecordError::InvalidRecordMode.into());
+        }
+        RecordMode::MerkleAccumulator => {
+            msg!("Merkle accumulator records must be initialized with InitializeMerkleAccumulator");
+            return Err(RecordError::InvalidRecordMode.into());
+        }
+        RecordMode::ContentPending | RecordMode::Content => {
+            msg!("Content-addressed records must be initialized with InitializeContent");
+            return Err(RecordError::InvalidRecordMode.into());
+        }
+        RecordMode::Standard
+        | RecordMode::AppendOnly
+        | RecordMode::Manifest
+        | RecordMode::KeyValue => {}
+    }
+
+    check_owner(data_info, program_id)?;
+    check_writable(data_info)?;
+    let raw_data = &mut data_info.data.borrow_mut();
+    let writable_start_index = initialize_record_v2(
+        raw_data,
+        authority_info.key,
+        mode,
+        extensions_length,
+        alignment,
+    )?;
+    if let Some(beneficiary) = rent_beneficiary {
+        let extensions = &mut raw_data[RecordDataV2::LEN..writable_start_index];
+        extension::init_extension::<RentBeneficiary>(extensions)?.beneficiary = *beneficiary;
+    }
+    let (header_length, too_small) = match mode {
+        RecordMode::Manifest => (ManifestHeader::LEN, RecordError::ManifestTooSmall),
+        RecordMode::KeyValue => (KeyValueHeader::LEN, RecordError::KeyValueTooSmall),
+        _ => return Ok(()),
+    };
+    if raw_data.len() < writable_start_index.saturating_add(header_length) {
+        msg!("Record account too small to hold the payload header");
+        return Err(too_small.into());
+    }
+    Ok(())
+}
+
+/// Checks that the lamports of a closed record go to its rent beneficiary, if
+/// it has one
+fn check_rent_beneficiary(extensions: &[u8], destination_info: &AccountInfo) -> ProgramResult {
+    match extension::get_extension::<RentBeneficiary>(extensions) {
+        Ok(rent_beneficiary) if rent_beneficiary.beneficiary != *destination_info.key => {
+            msg!("Record lamports must be refunded to its rent beneficiary");
+            Err(RecordError::IncorrectBeneficiary.into())
+        }
+        Err(error) if error != RecordError::ExtensionNotFound.into() => Err(error),
+        _ => Ok(()),
+    }
+}
+
+/// Sets the rent beneficiary of a record, initializing the extension in the
+/// free space of its extension region if it is not present yet
+fn set_rent_beneficiary<D: DerefMut<Target = [u8]>>(
+    record: &mut RecordAccount<D>,
+    beneficiary: &Address,
+) -> ProgramResult {
+    extension::get_or_init_extension::<RentBeneficiary>(record.extensions_mut())
+        .inspect_err(|_| msg!("Record has no room for a rent beneficiary"))?
+        .beneficiary = *beneficiary;
+    Ok(())
+}
+
+/// Checks a write to the record's payload against its write policy, if it
+/// has one, recording the write for the policy's rate limit
+fn check_write_policy<D: DerefMut<Target = [u8]>>(record: &mut RecordAccount<D>) -> ProgramResult {
+    let policy = match extension::get_extension_mut::<WritePolicy>(record.extensions_mut()) {
+        Ok(policy) => policy,
+        Err(error) if error == RecordError::ExtensionNotFound.into() => return Ok(()),
+        Err(error) => return Err(error),
+    };
+    let slot = Clock::get()?.slot;
+    policy.record_write(slot).map_err(|error| {
+        match error {
+            RecordError::WriteOutsideWindow => msg!(
+                "Record can only be written from slot {} to slot {}",
+                u64::from_le_bytes(policy.start_slot),
+                u64::from_le_bytes(policy.end_slot)
+            ),
+            _ => msg!(
+                "Record cannot be written again until slot {}",
+                u64::from_le_bytes(policy.next_write_slot)
+            ),
+        }
+        error.into()
+    })
+}
+
+/// Removes the token gate of a record whose authority was replaced, if it
+/// has one
+fn clear_token_gate<D: DerefMut<Target = [u8]>>(record: &mut RecordAccount<D>) -> ProgramResult {
+    match extension::get_extension_mut::<TokenGate>(record.extensions_mut()) {
+        Ok(gate) => {
+            *gate = TokenGate::default();
+            Ok(())
+        }
+        Err(error) if error == RecordError::ExtensionNotFound.into() => Ok(()),
+        Err(error) => Err(error),
+    }
+}
+
+/// Revokes every session key of a record whose authority was replaced, since
+/// they were delegated by the previous authority
+fn clear_session_keys<D: DerefMut<Target = [u8]>>(record: &mut RecordAccount<D>) -> ProgramResult {
+    match extension::get_extension_mut::<SessionKeys>(record.extensions_mut()) {
+        Ok(sessions) => {
+            *sessions = SessionKeys::default();
+            Ok(())
+        }
+        Err(error) if error == RecordError::ExtensionNotFound.into() => Ok(()),
+        Err(error) => Err(error),
+    }
+}
+
+/// Makes room for an extension in the extension region of a record, growing
+/// the region and shifting the payload if its free space is too small
+fn reserve_extension(
+    program_id: &Address,
+    data_info: &AccountInfo,
+    extension_type: ExtensionType,
+) -> ProgramResult {
+    let (writable_start_index, growth) = {
+        let record = RecordAccount::from_account_info(data_info, program_id)?;
+        let RecordHeader::V2(header) = record.header() else {
+            msg!("Records without the extended header cannot hold extensions");
+            return Err(RecordError::InvalidRecordMode.into());
+        };
+        let extensions = record.extensions();
+        if extension::get_extension_types(extensions)?.contains(&extension_type) {
+            return Ok(());
+        }
+        let free_space = extensions
+            .len()
+            .saturating_sub(extension::get_extensions_length(extensions)?);
+        // grow by whole multiples of the alignment, so an aligned payload
+        // stays aligned once shifted
+        (
+            record.writable_start_index(),
+            extension_type
+                .tlv_len()
+                .saturating_sub(free_space)
+                .next_multiple_of(header.alignment()),
+        )
+    };
+    if growth == 0 {
+        return Ok(());
+    }
+
+    let payload_end = data_info.data_len();
+    data_info.resize(payload_end.saturating_add(growth))?;
+    let mut record = RecordAccount::from_account_info_mut(data_info, program_id)?;
+    let raw_data = record.data_mut();
+    let new_writable_start_index = writable_start_index.saturating_add(growth);
+    raw_data.copy_within(writable_start_index..payload_end, new_writable_start_index);
+    raw_data[writable_start_index..new_writable_start_index].fill(0);
+    let header = record
+        .header_v2_mut()
+        .ok_or(ProgramError::InvalidAccountData)?;
+    header.extensions_length = u32::try_from(header.extensions_length().saturating_add(growth))
+        .map_err(|_| RecordError::Overflow)?
+        .to_le_bytes();
+    Ok(())
+}
+
+/// Reallocates a record to hold `data_length` bytes of payload, if the mode
+/// of the record allows it
+fn reallocate(
+    program_id: &Address,
+    data_info: &AccountInfo,
+    authority_info: &AccountInfo,
+    gate_accounts: &[AccountInfo],
+    data_length: u64,
+) -> ProgramResult {
+    let writable_start_index = {
+        let record = RecordAccount::from_account_info_mut(data_info, program_id)?;
+        check_record_authority(&record, authority_info, gate_accounts)?;
+        match record.mode()? {
+            RecordMode::Standard => {}
+            RecordMode::AppendOnly => {
+                if record.payload().len() as u64 > data_length {
+                    msg!("Append-only record data cannot be shrunk");
+                    return Err(RecordError::AppendOnlyViolation.into());
+                }
+            }
+            RecordMode::RingBuffer => {
+                msg!("Ring buffer records cannot be resized");
+                return Err(RecordError::InvalidRecordMode.into());
+            }
+            RecordMode::MerkleAccumulator => {
+                msg!("Merkle accumulator records cannot be resized");
+                return Err(RecordError::InvalidRecordMode.into());
+            }
+            RecordMode::Manifest => {
+                let shards_length = Manifest::unpack(record.data())?
+                    .shards
+                    .len()
+                    .saturating_mul(ShardEntry::LEN)
+                    .saturating_add(ManifestHeader::LEN);
+                if shards_length as u64 > data_length {
+                    msg!("Manifest records cannot be shrunk below their shard entries");
+                    return Err(RecordError::ShrinkBelowEntries.into());
+                }
+            }
+            RecordMode::ContentPending => {}
+            RecordMode::Content => {
+                msg!("Content-addressed records cannot be resized once finalized");
+                return Err(RecordError::RecordFrozen.into());
+            }
+            RecordMode::KeyValue => {
+                let entries_length = KeyValueMap::unpack(record.data())?
+                    .used_length()
+                    .saturating_add(KeyValueHeader::LEN);
+                if entries_length as u64 > data_length {
+                    msg!("Key-value records cannot be shrunk below their entries");
+                    return Err(RecordError::ShrinkBelowEntries.into());
+                }
+            }
+        }
+        record.writable_start_index()
+    };
+
+    // needed account length is the sum of the meta data length and the specified
+    // data length
+    let needed_account_length = usize::try_from(data_length)
+        .ok()
+        .and_then(|data_length| writable_start_index.checked_add(data_length))
+        .ok_or(RecordError::Overflow)?;
+
+    // reallocate
+    if data_info.data_len() >= needed_account_length {
+        msg!("no additional reallocation needed");
+        return Ok(());
+    }
+    msg!(
+        "reallocating +{:?} bytes",
+        needed_account_length
+            .checked_sub(data_info.data_len())
+            .unwrap(),
+    );
+    data_info.resize(needed_account_length)
+}
+
+/// Creates a rent-exempt account owned by the program at a program derived
+/// address
+fn create_pda_account<'a>(
+    program_id: &Address,
+    payer_info: &AccountInfo<'a>,
+    new_account_info: &AccountInfo<'a>,
+    system_program_info: &AccountInfo<'a>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+) -> ProgramResult {
+    let lamports = Rent::get()?.minimum_balance(space);
+    if new_account_info.lamports() == 0 {
+        return invoke_signed(
+            &system_instruction::create_account(
+                payer_info.key,
+                new_account_info.key,
+                lamports,
+                space as u64,
+                program_id,
+            ),
+            &[
+                payer_info.clone(),
+                new_account_info.clone(),
+                system_program_info.clone(),
+            ],
+            &[signer_seeds],
+        );
+    }
+
+    // The address was funded before creation, which would make
+    // `create_account` fail, so top it up and allocate it in place instead
+    let shortfall = lamports.saturating_sub(new_account_info.lamports());
+    if shortfall > 0 {
+        invoke(
+            &system_instruction::transfer(payer_info.key, new_account_info.key, shortfall),
+            &[
+                payer_info.clone(),
+                new_account_info.clone(),
+                system_program_info.clone(),
+            ],
+        )?;
+    }
+    invoke_signed(
+        &system_instruction::allocate(new_account_info.key, space as u64),
+        &[new_account_info.clone(), system_program_info.clone()],
+        &[signer_seeds],
+    )?;
+    invoke_signed(
+        &system_instruction::assign(new_account_info.key, program_id),
+        &[new_account_info.clone(), system_program_info.clone()],
+        &[signer_seeds],
+    )
+}
+
+/// Checks that a name can point at the given record, which must be an
+/// initialized record owned by the program
+fn check_named_record(program_id: &Address, record_info: &AccountInfo) -> ProgramResult {
+    RecordAccount::from_account_info(record_info, program_id).inspect_err(|_| {
+        msg!("Named account must be an initialized record owned by the program")
+    })?;
+    Ok(())
+}
+
+/// Checks the authority of a key-value record, returning its header and
+/// entries
+fn key_value_entries<'a, D: DerefMut<Target = [u8]>>(
+    record: &'a mut RecordAccount<D>,
+    authority_info: &AccountInfo,
+    gate_accounts: &[AccountInfo],
+) -> Result<(&'a mut KeyValueHeader, &'a mut [u8]), ProgramError> {
+    check_record_authority(record, authority_info, gate_accounts)?;
+    if record.mode()? != RecordMode::KeyValue {
+        msg!("Record is not a key-value record");
+        return Err(RecordError::InvalidRecordMode.into());
+    }
+    check_write_policy(record)?;
+
+    let (header, entries) = record
+        .payload_mut()
+        .split_at_mut_checked(KeyValueHeader::LEN)
+        .ok_or(ProgramError::InvalidAccountData)?;
+    let header = bytemuck::try_from_bytes_mut::<KeyValueHeader>(header)
+        .map_err(|_| ProgramError::InvalidArgument)?;
+    Ok((header, entries))
+}
+
+/// Sets the manifest entry at `index` to describe the given shard record,
+/// appending a new entry if `index` is the current shard count
+fn set_shard<'a>(
+    program_id: &Address,
+    manifest_info: &AccountInfo<'a>,
+    shard_info: &AccountInfo<'a>,
+    authority_info: &AccountInfo<'a>,
+    gate_accounts: &[AccountInfo<'a>],
+    index: Option<u32>,
+    hash: [u8; 32],
+) -> ProgramResult {
+    check_distinct(manifest_info, shard_info)?;
+
+    let mut manifest = RecordAccount::from_account_info_mut(manifest_info, program_id)?;
+    check_record_authority(&manifest, authority_info, gate_accounts)?;
+    let shard_length = {
+        let shard = RecordAccount::from_account_info(shard_info, program_id)
+            .inspect_err(|_| msg!("Shard must be an initialized record owned by the program"))?;
+        check_record_authority(&shard, authority_info, gate_accounts)?;
+        shard.payload().len() as u64
+    };
+    if manifest.mode()? != RecordMode::Manifest {
+        msg!("Record is not a manifest");
+        return Err(RecordError::InvalidRecordMode.into());
+    }
+    check_write_policy(&mut manifest)?;
+
+    let (header, entries) = manifest
+        .payload_mut()
+        .split_at_mut_checked(ManifestHeader::LEN)
+        .ok_or(ProgramError::InvalidAccountData)?;
+    let header = bytemuck::try_from_bytes_mut::<ManifestHeader>(header)
+        .map_err(|_| ProgramError::InvalidArgument)?;
+    let shard_count = header.shard_count();
+    let index = match index {
+        Some(index) if index < shard_count => index,
+        Some(_) => {
+            msg!("Shard index out of range");
+            return Err(RecordError::ShardIndexOutOfRange.into());
+        }
+        None => {
+            header.shard_count = shard_count
+                .checked_add(1)
+                .ok_or(RecordError::Overflow)?
+                .to_le_bytes();
+            shard_count
+        }
+    };
+    let start = (index as usize)
+        .checked_mul(ShardEntry::LEN)
+        .ok_or(RecordError::Overflow)?;
+    let entry = start
+        .checked_add(ShardEntry::LEN)
+        .and_then(|end| entries.get_mut(start..end))
+        .ok_or_else(|| {
+            msg!("Manifest record too small, reallocate before appending a shard");
+            RecordError::ManifestTooSmall
+        })?;
+    entry.copy_from_slice(bytemuck::bytes_of(&ShardEntry {
+        address: *shard_info.key,
+        length: shard_length.to_le_bytes(),
+        hash,
+    }));
+    Ok(())
+}
+
 /// Instruction processor
 pub fn process_instruction(
-    _program_id: &Address,
+    program_id: &Address,
     accounts: &[AccountInfo],
     input: &[u8],
 ) -> ProgramResult {
@@ -36,95 +786,853 @@ pub fn process_instruction(
 
             let data_info = next_account_info(account_info_iter)?;
             let authority_info = next_account_info(account_info_iter)?;
+            check_owner(data_info, program_id)?;
+            check_writable(data_info)?;
+            initialize_record(&mut data_info.data.borrow_mut(), authority_info.key)
+        }
 
-            let raw_data = &mut data_info.data.borrow_mut();
-            if raw_data.len() < RecordData::WRITABLE_START_INDEX {
-                return Err(ProgramError::InvalidAccountData);
+        RecordInstruction::Write { offset, data } => {
+            msg!("RecordInstruction::Write");
+            let data_info = next_account_info(account_info_iter)?;
+            let authority_info = next_account_info(account_info_iter)?;
+            let mut record = RecordAccount::from_account_info_mut(data_info, program_id)?;
+            check_write_signer(
+                &mut record,
+                authority_info,
+                account_info_iter.as_slice(),
+                data.len(),
+            )?;
+            let start = offset as usize;
+            let end = start.saturating_add(data.len());
+            if end > record.payload().len() {
+                msg!("Write extends past the end of the record");
+                Err(RecordError::WriteOutOfBounds.into())
+            } else {
+                check_write_mode(&mut record, offset, data.len())?;
+                check_write_policy(&mut record)?;
+                record.payload_mut()[start..end].copy_from_slice(data);
+                Ok(())
+            }
+        }
+
+        RecordInstruction::SetAuthority => {
+            msg!("RecordInstruction::SetAuthority");
+            let data_info = next_account_info(account_info_iter)?;
+            let authority_info = next_account_info(account_info_iter)?;
+            let new_authority_info = next_account_info(account_info_iter)?;
+            let mut record = RecordAccount::from_account_info_mut(data_info, program_id)?;
+            check_record_authority(&record, authority_info, account_info_iter.as_slice())?;
+            *record.authority_mut() = *new_authority_info.key;
+            clear_token_gate(&mut record)?;
+            clear_session_keys(&mut record)
+        }
+
+        RecordInstruction::CloseAccount => {
+            msg!("RecordInstruction::CloseAccount");
+            let data_info = next_account_info(account_info_iter)?;
+            let authority_info = next_account_info(account_info_iter)?;
+            let destination_info = next_account_info(account_info_iter)?;
+            check_distinct(data_info, destination_info)?;
+            check_writable(destination_info)?;
+            {
+                let record = RecordAccount::from_account_info_mut(data_info, program_id)?;
+                check_record_authority(&record, authority_info, account_info_iter.as_slice())?;
+                check_rent_beneficiary(record.extensions(), destination_info)?;
             }
+            let destination_starting_lamports = destination_info.lamports();
+            let data_lamports = data_info.lamports();
+            **data_info.lamports.borrow_mut() = 0;
+            **destination_info.lamports.borrow_mut() = destination_starting_lamports
+                .checked_add(data_lamports)
+                .ok_or(RecordError::Overflow)?;
+            Ok(())
+        }
 
-            let account_data = bytemuck::try_from_bytes_mut::<RecordData>(
-                &mut raw_data[..RecordData::WRITABLE_START_INDEX],
+        RecordInstruction::Reallocate { data_length } => {
+            msg!("RecordInstruction::Reallocate");
+            let data_info = next_account_info(account_info_iter)?;
+            let authority_info = next_account_info(account_info_iter)?;
+            reallocate(
+                program_id,
+                data_info,
+                authority_info,
+                account_info_iter.as_slice(),
+                data_length,
             )
-            .map_err(|_| ProgramError::InvalidArgument)?;
-            if account_data.is_initialized() {
-                msg!("Record account already initialized");
-                return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        RecordInstruction::WriteIfEqual {
+            check_offset,
+            expected,
+            write_offset,
+            data,
+        } => {
+            msg!("RecordInstruction::WriteIfEqual");
+            let data_info = next_account_info(account_info_iter)?;
+            let authority_info = next_account_info(account_info_iter)?;
+            let mut record = RecordAccount::from_account_info_mut(data_info, program_id)?;
+            check_record_authority(&record, authority_info, account_info_iter.as_slice())?;
+
+            let check_start = check_offset as usize;
+            let check_end = check_start.saturating_add(expected.len());
+            let current = record
+                .payload()
+                .get(check_start..check_end)
+                .ok_or_else(|| {
+                    msg!("Checked range extends past the end of the record");
+                    RecordError::WriteOutOfBounds
+                })?;
+            if current != expected {
+                msg!("Record data does not match the expected bytes");
+                return Err(RecordError::ExpectedDataMismatch.into());
             }
 
-            account_data.authority = *authority_info.key;
-            account_data.version = RecordData::CURRENT_VERSION;
+            let start = write_offset as usize;
+            let end = start.saturating_add(data.len());
+            if end > record.payload().len() {
+                msg!("Write extends past the end of the record");
+                return Err(RecordError::WriteOutOfBounds.into());
+            }
+            check_write_mode(&mut record, write_offset, data.len())?;
+            check_write_policy(&mut record)?;
+            record.payload_mut()[start..end].copy_from_slice(data);
             Ok(())
         }
 
-        RecordInstruction::Write { offset, data } => {
-            msg!("RecordInstruction::Write");
+        RecordInstruction::WriteMultiple { writes } => {
+            msg!("RecordInstruction::WriteMultiple");
+            let authority_info = next_account_info(account_info_iter)?;
+            let record_count = writes
+                .iter()
+                .map(|write| (write.record_index as usize).saturating_add(1))
+                .max()
+                .unwrap_or(0);
+            let (record_infos, gate_accounts) = account_info_iter
+                .as_slice()
+                .split_at_checked(record_count)
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+            if !authority_info.is_signer {
+                msg!("Record authority signature missing");
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+
+            // authorize the signer once, against the authority shared by
+            // every record
+            let mut shared_authority = None;
+            for (index, data_info) in record_infos.iter().enumerate() {
+                for other_info in &record_infos[..index] {
+                    check_distinct(data_info, other_info)?;
+                }
+                if !writes
+                    .iter()
+                    .any(|write| write.record_index as usize == index)
+                {
+                    msg!("Record account {} receives no writes", data_info.key);
+                    return Err(RecordError::UnwrittenRecord.into());
+                }
+                let mut record = RecordAccount::from_account_info_mut(data_info, program_id)?;
+                let record_authority = (*record.authority(), gating_token_program(&record)?);
+                match &shared_authority {
+                    None => {
+                        check_record_authority(&record, authority_info, gate_accounts)?;
+                        shared_authority = Some(record_authority);
+                    }
+                    Some(authority) if *authority != record_authority => {
+                        msg!("Records written together must share the same authority");
+                        return Err(RecordError::IncorrectAuthority.into());
+                    }
+                    Some(_) => {}
+                }
+                check_write_policy(&mut record)?;
+            }
+
+            // validate every write before applying any of them
+            let mut ranges = Vec::with_capacity(writes.len());
+            for write in &writes {
+                let data_info = &record_infos[write.record_index as usize];
+                let mut record = RecordAccount::from_account_info_mut(data_info, program_id)?;
+                let start = record
+                    .writable_start_index()
+                    .saturating_add(write.offset as usize);
+                let end = start.saturating_add(write.data.len());
+                if end > record.data().len() {
+                    msg!("Write extends past the end of the record");
+                    return Err(RecordError::WriteOutOfBounds.into());
+                }
+                check_write_mode(&mut record, write.offset, write.data.len())?;
+                ranges.push((data_info, start, end));
+            }
+
+            for ((data_info, start, end), write) in ranges.into_iter().zip(writes) {
+                data_info.data.borrow_mut()[start..end].copy_from_slice(write.data);
+            }
+            Ok(())
+        }
+
+        RecordInstruction::Commit { expected_hash } => {
+            msg!("RecordInstruction::Commit");
             let data_info = next_account_info(account_info_iter)?;
+            let buffer_info = next_account_info(account_info_iter)?;
             let authority_info = next_account_info(account_info_iter)?;
-            {
-                let raw_data = &data_info.data.borrow();
-                if raw_data.len() < RecordData::WRITABLE_START_INDEX {
-                    return Err(ProgramError::InvalidAccountData);
+            let destination_info = next_account_info(account_info_iter)?;
+            check_distinct(data_info, buffer_info)?;
+            check_distinct(data_info, destination_info)?;
+            check_distinct(buffer_info, destination_info)?;
+            check_writable(destination_info)?;
+
+            let (record_start_index, record_mode) = {
+                let mut record = RecordAccount::from_account_info_mut(data_info, program_id)?;
+                check_record_authority(&record, authority_info, account_info_iter.as_slice())?;
+                check_write_policy(&mut record)?;
+                (record.writable_start_index(), record.mode()?)
+            };
+            let buffer_start_index = {
+                let buffer = RecordAccount::from_account_info_mut(buffer_info, program_id)?;
+                check_record_authority(&buffer, authority_info, account_info_iter.as_slice())?;
+                check_rent_beneficiary(buffer.extensions(), destination_info)?;
+                buffer.writable_start_index()
+            };
+            match record_mode {
+                RecordMode::Standard | RecordMode::ContentPending => {}
+                RecordMode::AppendOnly => {
+                    msg!("Append-only record data cannot be replaced");
+                    return Err(RecordError::AppendOnlyViolation.into());
                 }
-                let account_data = bytemuck::try_from_bytes::<RecordData>(
-                    &raw_data[..RecordData::WRITABLE_START_INDEX],
-                )
-                .map_err(|_| ProgramError::InvalidArgument)?;
-                if !account_data.is_initialized() {
-                    msg!("Record account not initialized");
-                    return Err(ProgramError::UninitializedAccount);
+                RecordMode::RingBuffer => {
+                    msg!("Ring buffer records can only be modified by pushing entries");
+                    return Err(RecordError::InvalidRecordMode.into());
+                }
+                RecordMode::MerkleAccumulator => {
+                    msg!("Merkle accumulator records can only be modified by appending leaves");
+                    return Err(RecordError::InvalidRecordMode.into());
+                }
+                RecordMode::Manifest => {
+                    msg!("Manifest records can only be modified by appending or replacing shards");
+                    return Err(RecordError::InvalidRecordMode.into());
+                }
+                RecordMode::Content => {
+                    msg!("Content-addressed records cannot be modified once finalized");
+                    return Err(RecordError::RecordFrozen.into());
+                }
+                RecordMode::KeyValue => {
+                    msg!("Key-value records can only be modified by setting or deleting keys");
+                    return Err(RecordError::InvalidRecordMode.into());
                 }
-                check_authority(authority_info, &account_data.authority)?;
             }
-            let start = RecordData::WRITABLE_START_INDEX.saturating_add(offset as usize);
-            let end = start.saturating_add(data.len());
-            if end > data_info.data.borrow().len() {
-                Err(ProgramError::AccountDataTooSmall)
-            } else {
-                data_info.data.borrow_mut()[start..end].copy_from_slice(data);
-                Ok(())
+
+            let payload_length = buffer_info.data_len().saturating_sub(buffer_start_index);
+            if let Some(expected_hash) = expected_hash {
+                let hash = hash(&buffer_info.data.borrow()[buffer_start_index..]);
+                if hash.to_bytes() != expected_hash {
+                    msg!("Buffer payload does not match the expected hash");
+                    return Err(RecordError::HashMismatch.into());
+                }
             }
+
+            data_info.resize(record_start_index.saturating_add(payload_length))?;
+            data_info.data.borrow_mut()[record_start_index..]
+                .copy_from_slice(&buffer_info.data.borrow()[buffer_start_index..]);
+
+            let destination_starting_lamports = destination_info.lamports();
+            let buffer_lamports = buffer_info.lamports();
+            **buffer_info.lamports.borrow_mut() = 0;
+            **destination_info.lamports.borrow_mut() = destination_starting_lamports
+                .checked_add(buffer_lamports)
+                .ok_or(RecordError::Overflow)?;
+            Ok(())
         }
 
-        RecordInstruction::SetAuthority => {
-            msg!("RecordInstruction::SetAuthority");
+        RecordInstruction::InitializeWithMode { mode } => {
+            msg!("RecordInstruction::InitializeWithMode");
+
             let data_info = next_account_info(account_info_iter)?;
             let authority_info = next_account_info(account_info_iter)?;
-            let new_authority_info = next_account_info(account_info_iter)?;
+            initialize_with_mode(program_id, data_info, authority_info, mode, 0, 1, None)
+        }
+
+        RecordInstruction::InitializeRingBuffer { entry_size } => {
+            msg!("RecordInstruction::InitializeRingBuffer");
+
+            let data_info = next_account_info(account_info_iter)?;
+            let authority_info = next_account_info(account_info_iter)?;
+            if entry_size == 0 {
+                msg!("Ring buffer entry size must be non-zero");
+                return Err(RecordError::InvalidEntrySize.into());
+            }
+            check_owner(data_info, program_id)?;
+            check_writable(data_info)?;
+
             let raw_data = &mut data_info.data.borrow_mut();
-            if raw_data.len() < RecordData::WRITABLE_START_INDEX {
-                return Err(ProgramError::InvalidAccountData);
+            let header_start =
+                initialize_record_v2(raw_data, authority_info.key, RecordMode::RingBuffer, 0, 1)?;
+
+            let entries_length = raw_data
+                .len()
+                .saturating_sub(header_start)
+                .saturating_sub(RingBufferHeader::LEN);
+            let capacity = u32::try_from(entries_length.checked_div(entry_size as usize).unwrap())
+                .map_err(|_| ProgramError::InvalidAccountData)?;
+            if capacity == 0 {
+                msg!("Record account too small to hold a ring buffer entry");
+                return Err(RecordError::RingBufferTooSmall.into());
+            }
+            let header_end = header_start.saturating_add(RingBufferHeader::LEN);
+            raw_data[header_start..header_end].copy_from_slice(bytemuck::bytes_of(
+                &RingBufferHeader::new(entry_size, capacity),
+            ));
+            Ok(())
+        }
+
+        RecordInstruction::PushEntry { data } => {
+            msg!("RecordInstruction::PushEntry");
+            let data_info = next_account_info(account_info_iter)?;
+            let authority_info = next_account_info(account_info_iter)?;
+            let mut record = RecordAccount::from_account_info_mut(data_info, program_id)?;
+            check_record_authority(&record, authority_info, account_info_iter.as_slice())?;
+            if record.mode()? != RecordMode::RingBuffer {
+                msg!("Record is not a ring buffer");
+                return Err(RecordError::InvalidRecordMode.into());
+            }
+            check_write_policy(&mut record)?;
+
+            let (header, entries) = record
+                .payload_mut()
+                .split_at_mut_checked(RingBufferHeader::LEN)
+                .ok_or(ProgramError::InvalidAccountData)?;
+            let header = bytemuck::try_from_bytes_mut::<RingBufferHeader>(header)
+                .map_err(|_| ProgramError::InvalidArgument)?;
+            if data.len() != header.entry_size() as usize {
+                msg!("Entry does not match the ring buffer entry size");
+                return Err(RecordError::EntrySizeMismatch.into());
+            }
+            header.push(entries, data)
+        }
+
+        RecordInstruction::InitializeMerkleAccumulator { depth } => {
+            msg!("RecordInstruction::InitializeMerkleAccumulator");
+
+            let data_info = next_account_info(account_info_iter)?;
+            let authority_info = next_account_info(account_info_iter)?;
+            if depth == 0 || depth > merkle::MAX_DEPTH {
+                msg!(
+                    "Merkle accumulator depth must be between 1 and {}",
+                    merkle::MAX_DEPTH
+                );
+                return Err(ProgramError::InvalidArgument);
+            }
+            check_owner(data_info, program_id)?;
+            check_writable(data_info)?;
+
+            let raw_data = &mut data_info.data.borrow_mut();
+            let writable_start_index = initialize_record_v2(
+                raw_data,
+                authority_info.key,
+                RecordMode::MerkleAccumulator,
+                0,
+                1,
+            )?;
+
+            let (header, _) = raw_data[writable_start_index..]
+                .split_at_mut_checked(MerkleHeader::LEN)
+                .filter(|(_, frontier)| frontier.len() >= (depth as usize).saturating_mul(NODE_LEN))
+                .ok_or_else(|| {
+                    msg!("Record account too small to hold the Merkle accumulator frontier");
+                    RecordError::MerkleAccumulatorTooSmall
+                })?;
+            let header = bytemuck::try_from_bytes_mut::<MerkleHeader>(header)
+                .map_err(|_| ProgramError::InvalidArgument)?;
+            header.root = merkle::empty_root(depth);
+            header.leaf_count = [0; 8];
+            header.depth = depth;
+            Ok(())
+        }
+
+        RecordInstruction::AppendLeaf { leaf } => {
+            msg!("RecordInstruction::AppendLeaf");
+            let data_info = next_account_info(account_info_iter)?;
+            let authority_info = next_account_info(account_info_iter)?;
+            let mut record = RecordAccount::from_account_info_mut(data_info, program_id)?;
+            check_record_authority(&record, authority_info, account_info_iter.as_slice())?;
+            if record.mode()? != RecordMode::MerkleAccumulator {
+                msg!("Record is not a Merkle accumulator");
+                return Err(RecordError::InvalidRecordMode.into());
+            }
+            check_write_policy(&mut record)?;
+
+            let (header, frontier) = record
+                .payload_mut()
+                .split_at_mut_checked(MerkleHeader::LEN)
+                .ok_or(ProgramError::InvalidAccountData)?;
+            let header = bytemuck::try_from_bytes_mut::<MerkleHeader>(header)
+                .map_err(|_| ProgramError::InvalidArgument)?;
+            let frontier: &mut [Node] = bytemuck::cast_slice_mut(
+                frontier
+                    .get_mut(..header.frontier_len())
+                    .ok_or(ProgramError::InvalidAccountData)?,
+            );
+            let leaf_count = header.leaf_count();
+            header.root = merkle::append_leaf(frontier, leaf_count, &leaf)?;
+            header.leaf_count = leaf_count.saturating_add(1).to_le_bytes();
+            Ok(())
+        }
+
+        RecordInstruction::VerifyLeaf { leaf, index, proof } => {
+            msg!("RecordInstruction::VerifyLeaf");
+            let data_info = next_account_info(account_info_iter)?;
+            let record = RecordAccount::from_account_info(data_info, program_id)?;
+            let header = MerkleHeader::unpack(record.data())?;
+            if index >= header.leaf_count() || proof.len() != header.depth as usize {
+                msg!("Merkle inclusion proof does not match the record's tree");
+                return Err(RecordError::InvalidMerkleProof.into());
+            }
+            if merkle::proof_root(&leaf, index, proof) != header.root {
+                msg!("Merkle inclusion proof does not match the record's root");
+                return Err(RecordError::InvalidMerkleProof.into());
             }
-            let account_data = bytemuck::try_from_bytes_mut::<RecordData>(
-                &mut raw_data[..RecordData::WRITABLE_START_INDEX],
+            Ok(())
+        }
+
+        RecordInstruction::AppendShard { hash } => {
+            msg!("RecordInstruction::AppendShard");
+            let manifest_info = next_account_info(account_info_iter)?;
+            let shard_info = next_account_info(account_info_iter)?;
+            let authority_info = next_account_info(account_info_iter)?;
+            set_shard(
+                program_id,
+                manifest_info,
+                shard_info,
+                authority_info,
+                account_info_iter.as_slice(),
+                None,
+                hash,
             )
-            .map_err(|_| ProgramError::InvalidArgument)?;
-            if !account_data.is_initialized() {
-                msg!("Record account not initialized");
-                return Err(ProgramError::UninitializedAccount);
+        }
+
+        RecordInstruction::ReplaceShard { index, hash } => {
+            msg!("RecordInstruction::ReplaceShard");
+            let manifest_info = next_account_info(account_info_iter)?;
+            let shard_info = next_account_info(account_info_iter)?;
+            let authority_info = next_account_info(account_info_iter)?;
+            set_shard(
+                program_id,
+                manifest_info,
+                shard_info,
+                authority_info,
+                account_info_iter.as_slice(),
+                Some(index),
+                hash,
+            )
+        }
+
+        RecordInstruction::InitializeContent {
+            hash: content_hash,
+            data_length,
+        } => {
+            msg!("RecordInstruction::InitializeContent");
+            let payer_info = next_account_info(account_info_iter)?;
+            let data_info = next_account_info(account_info_iter)?;
+            let authority_info = next_account_info(account_info_iter)?;
+            let system_program_info = next_account_info(account_info_iter)?;
+
+            let (content_address, bump_seed) =
+                get_content_address_and_bump_seed(&content_hash, program_id);
+            if content_address != *data_info.key {
+                msg!("Record address does not match the content hash");
+                return Err(ProgramError::InvalidSeeds);
             }
-            check_authority(authority_info, &account_data.authority)?;
-            account_data.authority = *new_authority_info.key;
+            if !authority_info.is_signer {
+                msg!("Record authority signature missing");
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+
+            let space = usize::try_from(data_length)
+                .ok()
+                .and_then(|data_length| data_length.checked_add(RecordDataV2::LEN))
+                .ok_or(ProgramError::InvalidArgument)?;
+            create_pda_account(
+                program_id,
+                payer_info,
+                data_info,
+                system_program_info,
+                space,
+                &[CONTENT_SEED, &content_hash, &[bump_seed]],
+            )?;
+
+            let raw_data = &mut data_info.data.borrow_mut();
+            initialize_record_v2(
+                raw_data,
+                authority_info.key,
+                RecordMode::ContentPending,
+                0,
+                1,
+            )?;
             Ok(())
         }
 
-        RecordInstruction::CloseAccount => {
-            msg!("RecordInstruction::CloseAccount");
+        RecordInstruction::FinalizeContent { hash: content_hash } => {
+            msg!("RecordInstruction::FinalizeContent");
             let data_info = next_account_info(account_info_iter)?;
             let authority_info = next_account_info(account_info_iter)?;
+            let mut record = RecordAccount::from_account_info_mut(data_info, program_id)?;
+            check_record_authority(&record, authority_info, account_info_iter.as_slice())?;
+            if record.mode()? != RecordMode::ContentPending {
+                msg!("Record is not a pending content-addressed record");
+                return Err(RecordError::InvalidRecordMode.into());
+            }
+
+            let (content_address, _) = get_content_address_and_bump_seed(&content_hash, program_id);
+            if content_address != *data_info.key {
+                msg!("Record address does not match the content hash");
+                return Err(ProgramError::InvalidSeeds);
+            }
+            if hash(record.payload()).to_bytes() != content_hash {
+                msg!("Record payload does not match the content hash");
+                return Err(RecordError::HashMismatch.into());
+            }
+            let account_data = record
+                .header_v2_mut()
+                .ok_or(ProgramError::InvalidAccountData)?;
+            account_data.mode = RecordMode::Content as u8;
+            Ok(())
+        }
+
+        RecordInstruction::RegisterName { name } => {
+            msg!("RecordInstruction::RegisterName");
+            let payer_info = next_account_info(account_info_iter)?;
+            let name_info = next_account_info(account_info_iter)?;
+            let authority_info = next_account_info(account_info_iter)?;
+            let record_info = next_account_info(account_info_iter)?;
+            let system_program_info = next_account_info(account_info_iter)?;
+
+            if name.is_empty() || name.len() > NameRecord::MAX_NAME_LEN {
+                msg!("Name must be 1 to 32 bytes");
+                return Err(RecordError::InvalidName.into());
+            }
+            let (name_address, bump_seed) =
+                get_name_address_and_bump_seed(authority_info.key, name, program_id);
+            if name_address != *name_info.key {
+                msg!("Name account address does not match the authority and name");
+                return Err(ProgramError::InvalidSeeds);
+            }
+            if !authority_info.is_signer {
+                msg!("Record authority signature missing");
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+            check_named_record(program_id, record_info)?;
+
+            create_pda_account(
+                program_id,
+                payer_info,
+                name_info,
+                system_program_info,
+                NameRecord::LEN,
+                &[
+                    NAME_SEED,
+                    authority_info.key.as_ref(),
+                    name.as_bytes(),
+                    &[bump_seed],
+                ],
+            )?;
+            name_info
+                .data
+                .borrow_mut()
+                .copy_from_slice(bytemuck::bytes_of(&NameRecord {
+                    account_type: NameRecord::ACCOUNT_TYPE,
+                    authority: *authority_info.key,
+                    record: *record_info.key,
+                }));
+            Ok(())
+        }
+
+        RecordInstruction::RepointName => {
+            msg!("RecordInstruction::RepointName");
+            let name_info = next_account_info(account_info_iter)?;
+            let authority_info = next_account_info(account_info_iter)?;
+            let record_info = next_account_info(account_info_iter)?;
+            check_owner(name_info, program_id)?;
+            check_writable(name_info)?;
+            check_named_record(program_id, record_info)?;
+
+            let raw_data = &mut name_info.data.borrow_mut();
+            let name_record = bytemuck::try_from_bytes_mut::<NameRecord>(raw_data)
+                .ok()
+                .filter(|name_record| name_record.account_type == NameRecord::ACCOUNT_TYPE)
+                .ok_or(ProgramError::InvalidAccountData)?;
+            check_authority(authority_info, &name_record.authority)?;
+            name_record.record = *record_info.key;
+            Ok(())
+        }
+
+        RecordInstruction::ReleaseName => {
+            msg!("RecordInstruction::ReleaseName");
+            let name_info = next_account_info(account_info_iter)?;
+            let authority_info = next_account_info(account_info_iter)?;
             let destination_info = next_account_info(account_info_iter)?;
+            check_owner(name_info, program_id)?;
+            check_writable(name_info)?;
+            check_distinct(name_info, destination_info)?;
+            check_writable(destination_info)?;
+            {
+                let raw_data = &mut name_info.data.borrow_mut();
+                let name_record = NameRecord::unpack(raw_data)?;
+                check_authority(authority_info, &name_record.authority)?;
+                raw_data.fill(0);
+            }
+
+            let destination_starting_lamports = destination_info.lamports();
+            let name_lamports = name_info.lamports();
+            **name_info.lamports.borrow_mut() = 0;
+            **destination_info.lamports.borrow_mut() = destination_starting_lamports
+                .checked_add(name_lamports)
+                .ok_or(RecordError::Overflow)?;
+            Ok(())
+        }
+
+        RecordInstruction::SetKey { key, value } => {
+            msg!("RecordInstruction::SetKey");
+            let data_info = next_account_info(account_info_iter)?;
+            let authority_info = next_account_info(account_info_iter)?;
+            let mut record = RecordAccount::from_account_info_mut(data_info, program_id)?;
+            let (header, entries) =
+                key_value_entries(&mut record, authority_info, account_info_iter.as_slice())?;
+            header.set(entries, key, value)
+        }
+
+        RecordInstruction::DeleteKey { key } => {
+            msg!("RecordInstruction::DeleteKey");
+            let data_info = next_account_info(account_info_iter)?;
+            let authority_info = next_account_info(account_info_iter)?;
+            let mut record = RecordAccount::from_account_info_mut(data_info, program_id)?;
+            let (header, entries) =
+                key_value_entries(&mut record, authority_info, account_info_iter.as_slice())?;
+            header.delete(entries, key)
+        }
+
+        RecordInstruction::Migrate => {
+            msg!("RecordInstruction::Migrate");
+            let data_info = next_account_info(account_info_iter)?;
+            let authority_info = next_account_info(account_info_iter)?;
+            let authority = {
+                let record = RecordAccount::from_account_info_mut(data_info, program_id)?;
+                check_authority(authority_info, record.authority())?;
+                if record.header().version() == RecordDataV2::VERSION {
+                    msg!("Record already uses the extended header");
+                    return Err(RecordError::AlreadyMigrated.into());
+                }
+                *record.authority()
+            };
+
+            let payload_end = data_info.data_len();
+            let header_growth = RecordDataV2::LEN.saturating_sub(RecordData::WRITABLE_START_INDEX);
+            data_info.resize(payload_end.saturating_add(header_growth))?;
             let raw_data = &mut data_info.data.borrow_mut();
-            if raw_data.len() < RecordData::WRITABLE_START_INDEX {
-                return Err(ProgramError::InvalidAccountData);
+            raw_data.copy_within(
+                RecordData::WRITABLE_START_INDEX..payload_end,
+                RecordDataV2::LEN,
+            );
+            raw_data[..RecordDataV2::LEN].copy_from_slice(bytemuck::bytes_of(&RecordDataV2 {
+                version: RecordDataV2::VERSION,
+                authority,
+                mode: RecordMode::Standard as u8,
+                high_water_mark: [0; 8],
+                extensions_length: [0; 4],
+                alignment: 1,
+            }));
+            Ok(())
+        }
+
+        RecordInstruction::InitializeAligned { mode, alignment } => {
+            msg!("RecordInstruction::InitializeAligned");
+
+            let data_info = next_account_info(account_info_iter)?;
+            let authority_info = next_account_info(account_info_iter)?;
+            let padding = RecordDataV2::padding_for_alignment(alignment).inspect_err(|_| {
+                msg!(
+                    "Payload alignment must be a power of two up to {}",
+                    RecordDataV2::MAX_ALIGNMENT
+                );
+            })?;
+            initialize_with_mode(
+                program_id,
+                data_info,
+                authority_info,
+                mode,
+                padding,
+                alignment,
+                None,
+            )
+        }
+
+        RecordInstruction::InitializeChecked => {
+            msg!("RecordInstruction::InitializeChecked");
+
+            let data_info = next_account_info(account_info_iter)?;
+            let authority_info = next_account_info(account_info_iter)?;
+            check_owner(data_info, program_id)?;
+            check_writable(data_info)?;
+            if !authority_info.is_signer && !data_info.is_signer {
+                msg!("Record authority or record account signature missing");
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+            if *authority_info.key == Address::default() {
+                msg!("Record authority cannot be the default address");
+                return Err(RecordError::InvalidAuthority.into());
+            }
+            if !Rent::get()?.is_exempt(data_info.lamports(), data_info.data_len()) {
+                msg!("Record account must be rent-exempt");
+                return Err(ProgramError::AccountNotRentExempt);
             }
-            let account_data = bytemuck::try_from_bytes_mut::<RecordData>(
-                &mut raw_data[..RecordData::WRITABLE_START_INDEX],
+            initialize_record(&mut data_info.data.borrow_mut(), authority_info.key)
+        }
+
+        RecordInstruction::SetAuthorityChecked => {
+            msg!("RecordInstruction::SetAuthorityChecked");
+            let data_info = next_account_info(account_info_iter)?;
+            let authority_info = next_account_info(account_info_iter)?;
+            let new_authority_info = next_account_info(account_info_iter)?;
+            let mut record = RecordAccount::from_account_info_mut(data_info, program_id)?;
+            check_record_authority(&record, authority_info, account_info_iter.as_slice())?;
+            if new_authority_info.key == data_info.key
+                || *new_authority_info.key == solana_system_interface::program::ID
+            {
+                msg!("Record authority cannot be the record itself or the system program");
+                return Err(RecordError::InvalidAuthority.into());
+            }
+            if !new_authority_info.is_signer {
+                msg!("New record authority signature missing");
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+            *record.authority_mut() = *new_authority_info.key;
+            clear_token_gate(&mut record)?;
+            clear_session_keys(&mut record)
+        }
+
+        RecordInstruction::InitializeSponsored { mode } => {
+            msg!("RecordInstruction::InitializeSponsored");
+
+            let data_info = next_account_info(account_info_iter)?;
+            let authority_info = next_account_info(account_info_iter)?;
+            let beneficiary_info = next_account_info(account_info_iter)?;
+            initialize_with_mode(
+                program_id,
+                data_info,
+                authority_info,
+                mode,
+                ExtensionType::RentBeneficiary.tlv_len(),
+                1,
+                Some(beneficiary_info.key),
             )
-            .map_err(|_| ProgramError::InvalidArgument)?;
-            if !account_data.is_initialized() {
-                msg!("Record not initialized");
-                return Err(ProgramError::UninitializedAccount);
+        }
+
+        RecordInstruction::ReallocateFunded { data_length } => {
+            msg!("RecordInstruction::ReallocateFunded");
+            let data_info = next_account_info(account_info_iter)?;
+            let authority_info = next_account_info(account_info_iter)?;
+            let payer_info = next_account_info(account_info_iter)?;
+            let system_program_info = next_account_info(account_info_iter)?;
+            check_distinct(data_info, payer_info)?;
+            if !payer_info.is_signer {
+                msg!("Payer signature missing");
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+            let current_beneficiary = {
+                let record = RecordAccount::from_account_info(data_info, program_id)?;
+                match extension::get_extension::<RentBeneficiary>(record.extensions()) {
+                    Ok(rent_beneficiary) => Some(rent_beneficiary.beneficiary),
+                    Err(error) if error == RecordError::ExtensionNotFound.into() => None,
+                    Err(error) => return Err(error),
+                }
+            };
+            if let Some(beneficiary) = current_beneficiary.filter(|b| b != payer_info.key) {
+                let beneficiary_info = next_account_info(account_info_iter)?;
+                if *beneficiary_info.key != beneficiary {
+                    msg!("Current rent beneficiary must be passed after the system program");
+                    return Err(RecordError::IncorrectBeneficiary.into());
+                }
+                if !beneficiary_info.is_signer {
+                    msg!("Current rent beneficiary signature missing");
+                    return Err(ProgramError::MissingRequiredSignature);
+                }
+            }
+            reallocate(
+                program_id,
+                data_info,
+                authority_info,
+                account_info_iter.as_slice(),
+                data_length,
+            )?;
+
+            let rent = Rent::get()?;
+            if rent.minimum_balance(data_info.data_len()) <= data_info.lamports() {
+                return Ok(());
+            }
+            // the payer also funds the room its rent beneficiary extension needs
+            reserve_extension(program_id, data_info, ExtensionType::RentBeneficiary)?;
+            let rent_shortfall = rent
+                .minimum_balance(data_info.data_len())
+                .saturating_sub(data_info.lamports());
+            invoke(
+                &system_instruction::transfer(payer_info.key, data_info.key, rent_shortfall),
+                &[
+                    payer_info.clone(),
+                    data_info.clone(),
+                    system_program_info.clone(),
+                ],
+            )?;
+            let mut record = RecordAccount::from_account_info_mut(data_info, program_id)?;
+            set_rent_beneficiary(&mut record, payer_info.key)
+        }
+
+        RecordInstruction::SetExpiry { slot } => {
+            msg!("RecordInstruction::SetExpiry");
+            let data_info = next_account_info(account_info_iter)?;
+            let authority_info = next_account_info(account_info_iter)?;
+            {
+                let record = RecordAccount::from_account_info_mut(data_info, program_id)?;
+                check_record_authority(&record, authority_info, account_info_iter.as_slice())?;
+            }
+            reserve_extension(program_id, data_info, ExtensionType::Expiry)?;
+            let mut record = RecordAccount::from_account_info_mut(data_info, program_id)?;
+            *extension::get_or_init_extension::<Expiry>(record.extensions_mut())? =
+                Expiry::new(slot);
+            Ok(())
+        }
+
+        RecordInstruction::CloseExpired => {
+            msg!("RecordInstruction::CloseExpired");
+            let data_info = next_account_info(account_info_iter)?;
+            let destination_info = next_account_info(account_info_iter)?;
+            check_distinct(data_info, destination_info)?;
+            check_writable(destination_info)?;
+            {
+                let record = RecordAccount::from_account_info_mut(data_info, program_id)?;
+                let expiry_slot = extension::get_extension::<Expiry>(record.extensions())
+                    .inspect_err(|_| msg!("Record does not expire"))?
+                    .slot();
+                if Clock::get()?.slot < expiry_slot {
+                    msg!("Record expires at slot {}", expiry_slot);
+                    return Err(RecordError::RecordNotExpired.into());
+                }
+                let refund_address = match extension::get_extension::<RentBeneficiary>(
+                    record.extensions(),
+                ) {
+                    Ok(rent_beneficiary) => rent_beneficiary.beneficiary,
+                    Err(error) if error == RecordError::ExtensionNotFound.into() => {
+                        // the authority of a token-gated record is a mint,
+                        // which could never spend the refund
+                        if gating_token_program(&record)?.is_some() {
+                            msg!("Token-gated records need a rent beneficiary to be closed on expiry");
+                            return Err(RecordError::MissingRentBeneficiary.into());
+                        }
+                        *record.authority()
+                    }
+                    Err(error) => return Err(error),
+                };
+                if refund_address != *destination_info.key {
+                    msg!("Expired record lamports must go to its rent beneficiary or authority");
+                    return Err(RecordError::IncorrectBeneficiary.into());
+                }
             }
-            check_authority(authority_info, &account_data.authority)?;
             let destination_starting_lamports = destination_info.lamports();
             let data_lamports = data_info.lamports();
             **data_info.lamports.borrow_mut() = 0;
@@ -134,47 +1642,207 @@ pub fn process_instruction(
             Ok(())
         }
 
-        RecordInstruction::Reallocate { data_length } => {
-            msg!("RecordInstruction::Reallocate");
+        RecordInstruction::SetWritePolicy {
+            start_slot,
+            end_slot,
+            min_slot_gap,
+        } => {
+            msg!("RecordInstruction::SetWritePolicy");
             let data_info = next_account_info(account_info_iter)?;
             let authority_info = next_account_info(account_info_iter)?;
-
+            if start_slot > end_slot {
+                msg!("Write window must not end before it starts");
+                return Err(RecordError::InvalidWriteWindow.into());
+            }
             {
-                let raw_data = &mut data_info.data.borrow_mut();
-                if raw_data.len() < RecordData::WRITABLE_START_INDEX {
-                    return Err(ProgramError::InvalidAccountData);
+                let record = RecordAccount::from_account_info_mut(data_info, program_id)?;
+                check_record_authority(&record, authority_info, account_info_iter.as_slice())?;
+            }
+            reserve_extension(program_id, data_info, ExtensionType::WritePolicy)?;
+            let mut record = RecordAccount::from_account_info_mut(data_info, program_id)?;
+            let policy = extension::get_or_init_extension::<WritePolicy>(record.extensions_mut())?;
+            policy.start_slot = start_slot.to_le_bytes();
+            policy.end_slot = end_slot.to_le_bytes();
+            policy.min_slot_gap = min_slot_gap.to_le_bytes();
+            Ok(())
+        }
+
+        RecordInstruction::RegisterSessionKey {
+            expiry_slot,
+            byte_budget,
+        } => {
+            msg!("RecordInstruction::RegisterSessionKey");
+            let data_info = next_account_info(account_info_iter)?;
+            let authority_info = next_account_info(account_info_iter)?;
+            let session_key_info = next_account_info(account_info_iter)?;
+            let holder = {
+                let record = RecordAccount::from_account_info_mut(data_info, program_id)?;
+                check_record_authority(&record, authority_info, account_info_iter.as_slice())?;
+                if session_key_info.key == record.authority()
+                    || *session_key_info.key == Address::default()
+                {
+                    msg!("Session key must differ from the record authority");
+                    return Err(RecordError::InvalidAuthority.into());
                 }
-                let account_data = bytemuck::try_from_bytes_mut::<RecordData>(
-                    &mut raw_data[..RecordData::WRITABLE_START_INDEX],
-                )
-                .map_err(|_| ProgramError::InvalidArgument)?;
-                if !account_data.is_initialized() {
-                    msg!("Record not initialized");
-                    return Err(ProgramError::UninitializedAccount);
+                if gating_token_program(&record)?.is_some() {
+                    *authority_info.key
+                } else {
+                    Address::default()
                 }
-                check_authority(authority_info, &account_data.authority)?;
+            };
+            let slot = Clock::get()?.slot;
+            if expiry_slot <= slot {
+                msg!("Session key must expire after the current slot {}", slot);
+                return Err(ProgramError::InvalidArgument);
             }
+            reserve_extension(program_id, data_info, ExtensionType::SessionKeys)?;
+            let mut record = RecordAccount::from_account_info_mut(data_info, program_id)?;
+            extension::get_or_init_extension::<SessionKeys>(record.extensions_mut())?
+                .register(
+                    SessionKey {
+                        key: *session_key_info.key,
+                        expiry_slot: expiry_slot.to_le_bytes(),
+                        remaining_bytes: byte_budget.unwrap_or(u64::MAX).to_le_bytes(),
+                        holder,
+                    },
+                    slot,
+                )
+                .inspect_err(|_| {
+                    msg!(
+                        "Record already holds {} unexpired session keys",
+                        extension::MAX_SESSION_KEYS
+                    )
+                })?;
+            Ok(())
+        }
 
-            // needed account length is the sum of the meta data length and the specified
-            // data length
-            let needed_account_length = std::mem::size_of::<RecordData>()
-                .checked_add(
-                    usize::try_from(data_length).map_err(|_| ProgramError::InvalidArgument)?,
+        RecordInstruction::RevokeSessionKey => {
+            msg!("RecordInstruction::RevokeSessionKey");
+            let data_info = next_account_info(account_info_iter)?;
+            let authority_info = next_account_info(account_info_iter)?;
+            let session_key_info = next_account_info(account_info_iter)?;
+            let mut record = RecordAccount::from_account_info_mut(data_info, program_id)?;
+            check_record_authority(&record, authority_info, account_info_iter.as_slice())?;
+            let revoked = match extension::get_extension_mut::<SessionKeys>(record.extensions_mut())
+            {
+                Ok(sessions) => sessions.revoke(session_key_info.key),
+                Err(error) if error == RecordError::ExtensionNotFound.into() => false,
+                Err(error) => return Err(error),
+            };
+            if !revoked {
+                msg!("Session key is not registered for the record");
+                return Err(RecordError::SessionKeyNotFound.into());
+            }
+            Ok(())
+        }
+
+        RecordInstruction::SetTokenGatedAuthority => {
+            msg!("RecordInstruction::SetTokenGatedAuthority");
+            let data_info = next_account_info(account_info_iter)?;
+            let authority_info = next_account_info(account_info_iter)?;
+            let mint_info = next_account_info(account_info_iter)?;
+            {
+                let record = RecordAccount::from_account_info_mut(data_info, program_id)?;
+                check_record_authority(&record, authority_info, account_info_iter.as_slice())?;
+            }
+            let is_mint = if *mint_info.owner == spl_generic_token::token::id() {
+                spl_generic_token::token::Mint::valid_account_data(&mint_info.try_borrow_data()?)
+            } else if *mint_info.owner == spl_generic_token::token_2022::id() {
+                spl_generic_token::token_2022::Mint::valid_account_data(
+                    &mint_info.try_borrow_data()?,
                 )
-                .unwrap();
+            } else {
+                msg!("Mint must be owned by SPL Token or Token-2022");
+                return Err(ProgramError::IncorrectProgramId);
+            };
+            if !is_mint {
+                msg!("Account is not an initialized mint");
+                return Err(ProgramError::InvalidAccountData);
+            }
+            reserve_extension(program_id, data_info, ExtensionType::TokenGate)?;
+            let mut record = RecordAccount::from_account_info_mut(data_info, program_id)?;
+            extension::get_or_init_extension::<TokenGate>(record.extensions_mut())?.token_program =
+                *mint_info.owner;
+            *record.authority_mut() = *mint_info.key;
+            clear_session_keys(&mut record)
+        }
 
-            // reallocate
-            if data_info.data_len() >= needed_account_length {
-                msg!("no additional reallocation needed");
-                return Ok(());
+        RecordInstruction::WriteSigned {
+            offset,
+            nonce,
+            data,
+        } => {
+            msg!("RecordInstruction::WriteSigned");
+            let data_info = next_account_info(account_info_iter)?;
+            let instructions_sysvar_info = next_account_info(account_info_iter)?;
+            {
+                let record = RecordAccount::from_account_info_mut(data_info, program_id)?;
+                let signer = check_write_signature(
+                    instructions_sysvar_info,
+                    &write_signed_message(data_info.key, offset, nonce, data),
+                )?;
+                // the authority of a gated record is a mint, which cannot sign
+                match gating_token_program(&record)? {
+                    Some(token_program) => {
+                        check_token_holder(
+                            record.authority(),
+                            &token_program,
+                            &signer,
+                            account_info_iter.as_slice(),
+                        )?;
+                        next_account_info(account_info_iter)?;
+                    }
+                    None if signer != *record.authority() => {
+                        msg!("Write must be signed by the record authority");
+                        return Err(RecordError::IncorrectAuthority.into());
+                    }
+                    None => {}
+                }
             }
-            msg!(
-                "reallocating +{:?} bytes",
-                needed_account_length
-                    .checked_sub(data_info.data_len())
-                    .unwrap(),
-            );
-            data_info.resize(needed_account_length)?;
+            reserve_extension(program_id, data_info, ExtensionType::WriteNonce)?;
+            let rent_shortfall = Rent::get()?
+                .minimum_balance(data_info.data_len())
+                .saturating_sub(data_info.lamports());
+            if rent_shortfall > 0 {
+                let payer_info = next_account_info(account_info_iter)
+                    .inspect_err(|_| msg!("Payer required to fund the record's write nonce"))?;
+                let system_program_info = next_account_info(account_info_iter)?;
+                check_distinct(data_info, payer_info)?;
+                if !payer_info.is_signer {
+                    msg!("Payer signature missing");
+                    return Err(ProgramError::MissingRequiredSignature);
+                }
+                invoke(
+                    &system_instruction::transfer(payer_info.key, data_info.key, rent_shortfall),
+                    &[
+                        payer_info.clone(),
+                        data_info.clone(),
+                        system_program_info.clone(),
+                    ],
+                )?;
+            }
+            let mut record = RecordAccount::from_account_info_mut(data_info, program_id)?;
+            {
+                let write_nonce =
+                    extension::get_or_init_extension::<WriteNonce>(record.extensions_mut())?;
+                if write_nonce.nonce() != nonce {
+                    msg!("Expected nonce {}", write_nonce.nonce());
+                    return Err(RecordError::NonceMismatch.into());
+                }
+                write_nonce.nonce = nonce
+                    .checked_add(1)
+                    .ok_or(RecordError::Overflow)?
+                    .to_le_bytes();
+            }
+            let start = offset as usize;
+            let end = start.saturating_add(data.len());
+            if end > record.payload().len() {
+                msg!("Write extends past the end of the record");
+                return Err(RecordError::WriteOutOfBounds.into());
+            }
+            check_write_mode(&mut record, offset, data.len())?;
+            check_write_policy(&mut record)?;
+            record.payload_mut()[start..end].copy_from_slice(data);
             Ok(())
         }
     }
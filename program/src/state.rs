@@ -1,8 +1,19 @@
 //! Program state
 use {
+    crate::error::RecordError,
     bytemuck::{Pod, Zeroable},
+    num_derive::FromPrimitive,
+    num_traits::FromPrimitive,
+    solana_account_info::AccountInfo,
     solana_address::Address,
+    solana_program_error::ProgramError,
     solana_program_pack::IsInitialized,
+    solana_sha256_hasher::hash,
+    std::{
+        cell::{Ref, RefMut},
+        marker::PhantomData,
+        ops::{Deref, DerefMut, Range},
+    },
 };
 
 /// Header type for recorded account data
@@ -22,6 +33,19 @@ impl RecordData {
 
     /// Start of writable account data, after version and authority
     pub const WRITABLE_START_INDEX: usize = 33;
+
+    /// Start of writable account data of a record, after its header and any
+    /// extensions, from its full account data
+    ///
+    /// Fails on anything other than an initialized record, such as a name
+    /// account.
+    pub fn writable_start_index(account_data: &[u8]) -> Result<usize, ProgramError> {
+        let writable_start_index = RecordHeader::unpack(account_data)?.writable_start_index();
+        if writable_start_index > account_data.len() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(writable_start_index)
+    }
 }
 
 impl IsInitialized for RecordData {
@@ -31,6 +55,923 @@ impl IsInitialized for RecordData {
     }
 }
 
+/// Header type for records initialized with a mode, extending the fields of
+/// [`RecordData`]
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct RecordDataV2 {
+    /// Struct version, always `RecordDataV2::VERSION`
+    pub version: u8,
+
+    /// The account allowed to update the data
+    pub authority: Address,
+
+    /// The record's [`RecordMode`], fixed at initialization
+    pub mode: u8,
+
+    /// Little-endian `u64` payload length written so far, for append-only
+    /// records
+    pub high_water_mark: [u8; 8],
+
+    /// Little-endian `u32` length of the extension region between the header
+    /// and the payload
+    pub extensions_length: [u8; 4],
+
+    /// Alignment of the payload offset, kept as the extension region grows
+    pub alignment: u8,
+}
+
+impl RecordDataV2 {
+    /// Version of records initialized with a mode
+    pub const VERSION: u8 = 2;
+
+    /// Length of the extended header, after which the extension region starts
+    pub const LEN: usize = 47;
+
+    /// Largest alignment the payload offset can be padded to
+    pub const MAX_ALIGNMENT: u8 = 64;
+
+    /// Unpack the extended header from the full account data of a record
+    pub fn unpack(account_data: &[u8]) -> Result<&Self, ProgramError> {
+        account_data
+            .get(..Self::LEN)
+            .and_then(|bytes| bytemuck::try_from_bytes::<Self>(bytes).ok())
+            .filter(|record_data| record_data.version == Self::VERSION)
+            .ok_or(ProgramError::InvalidAccountData)
+    }
+
+    /// The record's mode
+    pub fn mode(&self) -> Result<RecordMode, ProgramError> {
+        RecordMode::from_u8(self.mode).ok_or(ProgramError::InvalidAccountData)
+    }
+
+    /// Payload length written so far, for append-only records
+    pub fn high_water_mark(&self) -> u64 {
+        u64::from_le_bytes(self.high_water_mark)
+    }
+
+    /// Update the payload length written so far
+    pub fn set_high_water_mark(&mut self, high_water_mark: u64) {
+        self.high_water_mark = high_water_mark.to_le_bytes();
+    }
+
+    /// Length of the extension region
+    pub fn extensions_length(&self) -> usize {
+        u32::from_le_bytes(self.extensions_length) as usize
+    }
+
+    /// Alignment of the payload offset, records initialized before it was
+    /// stored are unaligned
+    pub fn alignment(&self) -> usize {
+        (self.alignment as usize).max(1)
+    }
+
+    /// Start of writable account data, after the header and the extension
+    /// region
+    pub fn writable_start_index(&self) -> usize {
+        Self::LEN.saturating_add(self.extensions_length())
+    }
+
+    /// Length of the free space padding an empty extension region so that
+    /// the payload starts at an offset aligned to `alignment`, which must be
+    /// a power of two up to `MAX_ALIGNMENT`
+    pub fn padding_for_alignment(alignment: u8) -> Result<usize, ProgramError> {
+        if !alignment.is_power_of_two() || alignment > Self::MAX_ALIGNMENT {
+            return Err(ProgramError::InvalidArgument);
+        }
+        Ok(Self::LEN
+            .next_multiple_of(alignment as usize)
+            .saturating_sub(Self::LEN))
+    }
+}
+
+/// Header of an initialized record, in any of the supported versions
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RecordHeader<'a> {
+    /// Header of records initialized without a mode
+    V1(&'a RecordData),
+
+    /// Extended header of records initialized with a mode
+    V2(&'a RecordDataV2),
+}
+
+impl<'a> RecordHeader<'a> {
+    /// Unpack the header from the full account data of a record, according
+    /// to its version
+    pub fn unpack(account_data: &'a [u8]) -> Result<Self, ProgramError> {
+        match account_data.first() {
+            Some(&RecordData::CURRENT_VERSION) => account_data
+                .get(..RecordData::WRITABLE_START_INDEX)
+                .and_then(|bytes| bytemuck::try_from_bytes::<RecordData>(bytes).ok())
+                .map(Self::V1)
+                .ok_or(ProgramError::InvalidAccountData),
+            Some(&RecordDataV2::VERSION) => RecordDataV2::unpack(account_data).map(Self::V2),
+            Some(0) => Err(RecordError::UninitializedRecord.into()),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+
+    /// Version of the header
+    pub fn version(&self) -> u8 {
+        match self {
+            Self::V1(record_data) => record_data.version,
+            Self::V2(record_data) => record_data.version,
+        }
+    }
+
+    /// The account allowed to update the data
+    pub fn authority(&self) -> &'a Address {
+        match self {
+            Self::V1(record_data) => &record_data.authority,
+            Self::V2(record_data) => &record_data.authority,
+        }
+    }
+
+    /// The record's mode, records without the extended header are always in
+    /// standard mode
+    pub fn mode(&self) -> Result<RecordMode, ProgramError> {
+        match self {
+            Self::V1(_) => Ok(RecordMode::Standard),
+            Self::V2(record_data) => record_data.mode(),
+        }
+    }
+
+    /// Start of writable account data, after the header and any extensions
+    pub fn writable_start_index(&self) -> usize {
+        match self {
+            Self::V1(_) => RecordData::WRITABLE_START_INDEX,
+            Self::V2(record_data) => record_data.writable_start_index(),
+        }
+    }
+}
+
+/// Validated view over the account data of an initialized record
+///
+/// The data is checked once to hold an initialized header of a supported
+/// version, with its extension region inside the account, before giving
+/// access to the header, the extensions and the payload. The data is either
+/// a byte slice or borrowed from an `AccountInfo`, whose owner is checked.
+#[derive(Debug)]
+pub struct RecordAccount<D> {
+    data: D,
+    version: u8,
+    writable_start_index: usize,
+}
+
+impl<D: Deref<Target = [u8]>> RecordAccount<D> {
+    /// Validate the full account data of a record
+    pub fn unpack(data: D) -> Result<Self, ProgramError> {
+        if data.first() == Some(&NameRecord::ACCOUNT_TYPE) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if data.len() < RecordData::WRITABLE_START_INDEX {
+            return Err(RecordError::HeaderTooSmall.into());
+        }
+        if !matches!(data[0], RecordData::CURRENT_VERSION | RecordDataV2::VERSION) {
+            return Err(RecordError::UninitializedRecord.into());
+        }
+        let header = RecordHeader::unpack(&data)?;
+        let version = header.version();
+        let writable_start_index = header.writable_start_index();
+        if writable_start_index > data.len() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(Self {
+            data,
+            version,
+            writable_start_index,
+        })
+    }
+
+    /// The record header, in the version checked in `unpack`
+    pub fn header(&self) -> RecordHeader<'_> {
+        // the data was checked in unpack to be long enough for the header
+        if self.version == RecordDataV2::VERSION {
+            RecordHeader::V2(bytemuck::from_bytes(&self.data[..RecordDataV2::LEN]))
+        } else {
+            RecordHeader::V1(bytemuck::from_bytes(
+                &self.data[..RecordData::WRITABLE_START_INDEX],
+            ))
+        }
+    }
+
+    /// The account allowed to update the data
+    pub fn authority(&self) -> &Address {
+        self.header().authority()
+    }
+
+    /// The record's mode, records without the extended header are always in
+    /// standard mode
+    pub fn mode(&self) -> Result<RecordMode, ProgramError> {
+        self.header().mode()
+    }
+
+    /// Start of the payload within the account data
+    pub fn writable_start_index(&self) -> usize {
+        self.writable_start_index
+    }
+
+    /// Full account data of the record
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Extension region of the record, empty for records without the
+    /// extended header
+    pub fn extensions(&self) -> &[u8] {
+        self.data
+            .get(RecordDataV2::LEN..self.writable_start_index)
+            .unwrap_or_default()
+    }
+
+    /// Payload of the record
+    pub fn payload(&self) -> &[u8] {
+        &self.data[self.writable_start_index..]
+    }
+}
+
+impl<D: DerefMut<Target = [u8]>> RecordAccount<D> {
+    /// Mutable account allowed to update the data
+    pub fn authority_mut(&mut self) -> &mut Address {
+        // both header versions start with the version and authority
+        &mut bytemuck::from_bytes_mut::<RecordData>(
+            &mut self.data[..RecordData::WRITABLE_START_INDEX],
+        )
+        .authority
+    }
+
+    /// Mutable extended header, if the record has one
+    pub fn header_v2_mut(&mut self) -> Option<&mut RecordDataV2> {
+        if self.version != RecordDataV2::VERSION {
+            return None;
+        }
+        Some(bytemuck::from_bytes_mut(
+            &mut self.data[..RecordDataV2::LEN],
+        ))
+    }
+
+    /// Mutable full account data of the record, including its header
+    pub(crate) fn data_mut(&mut self) -> &mut [u8] {
+        &mut self.data
+    }
+
+    /// Mutable extension region of the record
+    pub fn extensions_mut(&mut self) -> &mut [u8] {
+        let writable_start_index = self.writable_start_index;
+        self.data
+            .get_mut(RecordDataV2::LEN..writable_start_index)
+            .unwrap_or_default()
+    }
+
+    /// Mutable payload of the record
+    pub fn payload_mut(&mut self) -> &mut [u8] {
+        &mut self.data[self.writable_start_index..]
+    }
+}
+
+impl<'a> RecordAccount<Ref<'a, [u8]>> {
+    /// Borrow and validate the data of a record account, which must be owned
+    /// by the program
+    pub fn from_account_info(
+        account_info: &'a AccountInfo,
+        program_id: &Address,
+    ) -> Result<Self, ProgramError> {
+        if account_info.owner != program_id {
+            return Err(RecordError::IncorrectOwner.into());
+        }
+        let data = account_info.try_borrow_data()?;
+        Self::unpack(Ref::map(data, |data| &**data))
+    }
+}
+
+impl<'a> RecordAccount<RefMut<'a, [u8]>> {
+    /// Mutably borrow and validate the data of a record account, which must
+    /// be owned by the program and writable
+    pub fn from_account_info_mut(
+        account_info: &'a AccountInfo,
+        program_id: &Address,
+    ) -> Result<Self, ProgramError> {
+        if account_info.owner != program_id {
+            return Err(RecordError::IncorrectOwner.into());
+        }
+        if !account_info.is_writable {
+            return Err(RecordError::AccountNotWritable.into());
+        }
+        let data = account_info.try_borrow_mut_data()?;
+        Self::unpack(RefMut::map(data, |data| &mut **data))
+    }
+}
+
+/// View the start of an initialized record's payload as a `Pod` type, from
+/// the full account data of the record
+///
+/// Fails if the payload is too small or not aligned for the type, records
+/// initialized with `InitializeAligned` have their payload at an aligned
+/// offset.
+pub fn payload_as<T: Pod>(account_data: &[u8]) -> Result<&T, ProgramError> {
+    let start = RecordData::writable_start_index(account_data)?;
+    start
+        .checked_add(size_of::<T>())
+        .and_then(|end| account_data.get(start..end))
+        .ok_or(ProgramError::AccountDataTooSmall)
+        .and_then(|bytes| {
+            bytemuck::try_from_bytes(bytes).map_err(|_| ProgramError::InvalidAccountData)
+        })
+}
+
+/// View the start of an initialized record's payload as a mutable `Pod`
+/// type, from the full account data of the record
+pub fn payload_as_mut<T: Pod>(account_data: &mut [u8]) -> Result<&mut T, ProgramError> {
+    let start = RecordData::writable_start_index(account_data)?;
+    start
+        .checked_add(size_of::<T>())
+        .and_then(|end| account_data.get_mut(start..end))
+        .ok_or(ProgramError::AccountDataTooSmall)
+        .and_then(|bytes| {
+            bytemuck::try_from_bytes_mut(bytes).map_err(|_| ProgramError::InvalidAccountData)
+        })
+}
+
+/// Read-only view over a record whose payload starts with a `T`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TypedRecord<'a, T> {
+    /// The record header
+    pub header: RecordHeader<'a>,
+    payload: &'a [u8],
+    _value: PhantomData<T>,
+}
+
+impl<'a, T: Pod> TypedRecord<'a, T> {
+    /// Unpack the full account data of an initialized record holding a `T`
+    pub fn unpack(account_data: &'a [u8]) -> Result<Self, ProgramError> {
+        let header = RecordHeader::unpack(account_data)?;
+        let payload = account_data
+            .get(header.writable_start_index()..)
+            .and_then(|payload| payload.get(..size_of::<T>()))
+            .ok_or(ProgramError::AccountDataTooSmall)?;
+        Ok(Self {
+            header,
+            payload,
+            _value: PhantomData,
+        })
+    }
+
+    /// Copy the value out of the payload, whatever its alignment
+    pub fn read(&self) -> T {
+        bytemuck::pod_read_unaligned(self.payload)
+    }
+
+    /// Reference the value in place, failing if the payload is not aligned
+    /// for `T`
+    pub fn get(&self) -> Result<&'a T, ProgramError> {
+        bytemuck::try_from_bytes(self.payload).map_err(|_| ProgramError::InvalidAccountData)
+    }
+}
+
+/// Payload of a record initialized with the given mode, from its full account
+/// data
+fn mode_payload(account_data: &[u8], mode: RecordMode) -> Result<&[u8], ProgramError> {
+    let record_data = RecordDataV2::unpack(account_data)?;
+    if record_data.mode()? != mode {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    account_data
+        .get(record_data.writable_start_index()..)
+        .ok_or(ProgramError::InvalidAccountData)
+}
+
+/// Modes a record can be initialized with
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive)]
+pub enum RecordMode {
+    /// Any part of the payload may be rewritten
+    Standard,
+
+    /// Writes are only accepted at or beyond the high-water mark, and the
+    /// payload may never shrink
+    AppendOnly,
+
+    /// The payload holds a [`RingBufferHeader`] followed by fixed-size
+    /// entries, only modified by pushing new entries
+    RingBuffer,
+
+    /// The payload holds a [`MerkleHeader`] followed by the tree's frontier,
+    /// only modified by appending leaves
+    MerkleAccumulator,
+
+    /// The payload holds a [`ManifestHeader`] followed by [`ShardEntry`]s
+    /// listing the records a larger payload is split across, only modified
+    /// by appending or replacing shards
+    Manifest,
+
+    /// Content-addressed record still being written, which may be written
+    /// and resized until finalized
+    ContentPending,
+
+    /// Content-addressed record whose payload was verified to match the hash
+    /// its address is derived from, and can no longer be modified
+    Content,
+
+    /// The payload holds a [`KeyValueHeader`] followed by key-value entries
+    /// sorted by key, only modified by setting or deleting keys
+    KeyValue,
+}
+
+/// Header stored at the start of the payload of ring buffer records
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct RingBufferHeader {
+    /// Little-endian `u32` size of each entry
+    pub entry_size: [u8; 4],
+
+    /// Little-endian `u32` number of entries the record can hold
+    pub capacity: [u8; 4],
+
+    /// Little-endian `u32` index of the slot the next entry is written to
+    pub head: [u8; 4],
+
+    /// Little-endian `u32` number of entries currently stored
+    pub count: [u8; 4],
+}
+
+impl RingBufferHeader {
+    /// Length of the ring buffer header
+    pub const LEN: usize = 16;
+
+    /// Create a header for an empty ring buffer
+    pub fn new(entry_size: u32, capacity: u32) -> Self {
+        Self {
+            entry_size: entry_size.to_le_bytes(),
+            capacity: capacity.to_le_bytes(),
+            head: [0; 4],
+            count: [0; 4],
+        }
+    }
+
+    /// Size of each entry
+    pub fn entry_size(&self) -> u32 {
+        u32::from_le_bytes(self.entry_size)
+    }
+
+    /// Number of entries the record can hold
+    pub fn capacity(&self) -> u32 {
+        u32::from_le_bytes(self.capacity)
+    }
+
+    /// Index of the slot the next entry is written to
+    pub fn head(&self) -> u32 {
+        u32::from_le_bytes(self.head)
+    }
+
+    /// Number of entries currently stored
+    pub fn count(&self) -> u32 {
+        u32::from_le_bytes(self.count)
+    }
+
+    /// Byte range of the given slot within the entries
+    fn slot_range(&self, slot: u32) -> Option<Range<usize>> {
+        let entry_size = self.entry_size() as usize;
+        let start = (slot as usize).checked_mul(entry_size)?;
+        Some(start..start.checked_add(entry_size)?)
+    }
+
+    /// Write an entry into the slot at the head, overwriting the oldest entry
+    /// once the ring buffer is full
+    pub fn push(&mut self, entries: &mut [u8], entry: &[u8]) -> Result<(), ProgramError> {
+        if entry.len() != self.entry_size() as usize {
+            return Err(RecordError::EntrySizeMismatch.into());
+        }
+        let capacity = self.capacity();
+        let head = self.head();
+        if head >= capacity {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        self.slot_range(head)
+            .and_then(|range| entries.get_mut(range))
+            .ok_or(ProgramError::InvalidAccountData)?
+            .copy_from_slice(entry);
+        self.head = head
+            .saturating_add(1)
+            .checked_rem(capacity)
+            .ok_or(ProgramError::InvalidAccountData)?
+            .to_le_bytes();
+        self.count = self.count().saturating_add(1).min(capacity).to_le_bytes();
+        Ok(())
+    }
+}
+
+/// Read-only view over the account data of a ring buffer record
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RingBuffer<'a> {
+    /// The ring buffer header
+    pub header: &'a RingBufferHeader,
+    entries: &'a [u8],
+}
+
+impl<'a> RingBuffer<'a> {
+    /// Unpack the full account data of a ring buffer record
+    pub fn unpack(account_data: &'a [u8]) -> Result<Self, ProgramError> {
+        let payload = mode_payload(account_data, RecordMode::RingBuffer)?;
+        if payload.len() < RingBufferHeader::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let (header, entries) = payload.split_at(RingBufferHeader::LEN);
+        let header = bytemuck::try_from_bytes::<RingBufferHeader>(header)
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let capacity = header.capacity();
+        if capacity == 0 || header.head() >= capacity || header.count() > capacity {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let entries = header
+            .slot_range(capacity.saturating_sub(1))
+            .and_then(|range| entries.get(..range.end))
+            .ok_or(ProgramError::InvalidAccountData)?;
+        Ok(Self { header, entries })
+    }
+
+    /// Number of entries currently stored
+    pub fn len(&self) -> usize {
+        self.header.count() as usize
+    }
+
+    /// Whether no entries have been pushed yet
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Iterate over the stored entries, from oldest to newest
+    pub fn iter(&self) -> impl Iterator<Item = &'a [u8]> + '_ {
+        let capacity = self.header.capacity();
+        let count = self.header.count();
+        // capacity is non-zero and count is at most capacity, checked in unpack
+        let oldest = self
+            .header
+            .head()
+            .saturating_add(capacity)
+            .saturating_sub(count);
+        (0..count).map(move |i| {
+            let slot = oldest.saturating_add(i).checked_rem(capacity).unwrap();
+            let range = self.header.slot_range(slot).unwrap();
+            &self.entries[range]
+        })
+    }
+}
+
+/// Header stored at the start of the payload of Merkle accumulator records,
+/// followed by `depth` frontier nodes of 32 bytes each
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct MerkleHeader {
+    /// Root of the tree over all appended leaves
+    pub root: [u8; 32],
+
+    /// Little-endian `u64` number of leaves appended
+    pub leaf_count: [u8; 8],
+
+    /// Depth of the tree, fixed at initialization
+    pub depth: u8,
+}
+
+impl MerkleHeader {
+    /// Length of the Merkle header
+    pub const LEN: usize = 41;
+
+    /// Number of leaves appended
+    pub fn leaf_count(&self) -> u64 {
+        u64::from_le_bytes(self.leaf_count)
+    }
+
+    /// Length of the frontier stored after the header
+    pub fn frontier_len(&self) -> usize {
+        (self.depth as usize).saturating_mul(32)
+    }
+
+    /// Unpack the header from the full account data of a Merkle accumulator
+    /// record
+    pub fn unpack(account_data: &[u8]) -> Result<&Self, ProgramError> {
+        mode_payload(account_data, RecordMode::MerkleAccumulator)?
+            .get(..Self::LEN)
+            .and_then(|bytes| bytemuck::try_from_bytes::<Self>(bytes).ok())
+            .ok_or(ProgramError::InvalidAccountData)
+    }
+}
+
+/// Header stored at the start of the payload of manifest records
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct ManifestHeader {
+    /// Little-endian `u32` number of shards listed
+    pub shard_count: [u8; 4],
+}
+
+impl ManifestHeader {
+    /// Length of the manifest header
+    pub const LEN: usize = 4;
+
+    /// Number of shards listed
+    pub fn shard_count(&self) -> u32 {
+        u32::from_le_bytes(self.shard_count)
+    }
+}
+
+/// Entry of a manifest record, describing one shard of the full payload
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct ShardEntry {
+    /// Address of the shard record
+    pub address: Address,
+
+    /// Little-endian `u64` length of the shard's payload
+    pub length: [u8; 8],
+
+    /// SHA-256 hash of the shard's payload, as declared by the authority
+    pub hash: [u8; 32],
+}
+
+impl ShardEntry {
+    /// Length of a shard entry
+    pub const LEN: usize = 72;
+
+    /// Length of the shard's payload
+    pub fn length(&self) -> u64 {
+        u64::from_le_bytes(self.length)
+    }
+}
+
+/// Read-only view over the account data of a manifest record
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Manifest<'a> {
+    /// The shards listed by the manifest, in payload order
+    pub shards: &'a [ShardEntry],
+}
+
+impl<'a> Manifest<'a> {
+    /// Unpack the full account data of a manifest record
+    pub fn unpack(account_data: &'a [u8]) -> Result<Self, ProgramError> {
+        let (header, entries) = mode_payload(account_data, RecordMode::Manifest)?
+            .split_at_checked(ManifestHeader::LEN)
+            .ok_or(ProgramError::InvalidAccountData)?;
+        let header = bytemuck::try_from_bytes::<ManifestHeader>(header)
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let shards = (header.shard_count() as usize)
+            .checked_mul(ShardEntry::LEN)
+            .and_then(|length| entries.get(..length))
+            .ok_or(ProgramError::InvalidAccountData)?;
+        Ok(Self {
+            shards: bytemuck::cast_slice(shards),
+        })
+    }
+
+    /// Total length of the payload split across the shards
+    pub fn payload_len(&self) -> u64 {
+        self.shards
+            .iter()
+            .fold(0u64, |total, shard| total.saturating_add(shard.length()))
+    }
+
+    /// Reassemble the full payload from the account data of each shard
+    /// record, given in manifest order along with their addresses
+    ///
+    /// Fails if the shards do not match the manifest's addresses, lengths
+    /// and hashes.
+    pub fn reassemble<'b>(
+        &self,
+        shards: impl IntoIterator<Item = (&'b Address, &'b [u8])>,
+    ) -> Result<Vec<u8>, ProgramError> {
+        let mut payload = Vec::new();
+        let mut shards = shards.into_iter();
+        for entry in self.shards {
+            let (address, account_data) = shards.next().ok_or(RecordError::ShardMismatch)?;
+            let shard = RecordAccount::unpack(account_data)?;
+            let shard_payload = shard.payload();
+            if *address != entry.address
+                || shard_payload.len() as u64 != entry.length()
+                || hash(shard_payload).to_bytes() != entry.hash
+            {
+                return Err(RecordError::ShardMismatch.into());
+            }
+            payload.extend_from_slice(shard_payload);
+        }
+        if shards.next().is_some() {
+            return Err(RecordError::ShardMismatch.into());
+        }
+        Ok(payload)
+    }
+}
+
+/// Header stored at the start of the payload of key-value records
+///
+/// Each entry holds a `u8` key length and a little-endian `u32` value length,
+/// followed by the key and the value. Entries are stored contiguously and
+/// sorted by key.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct KeyValueHeader {
+    /// Little-endian `u32` number of bytes used by the entries
+    pub used_length: [u8; 4],
+}
+
+impl KeyValueHeader {
+    /// Length of the key-value header
+    pub const LEN: usize = 4;
+
+    /// Length of the header of each entry
+    pub const ENTRY_HEADER_LEN: usize = 5;
+
+    /// Number of bytes used by the entries
+    pub fn used_length(&self) -> u32 {
+        u32::from_le_bytes(self.used_length)
+    }
+
+    /// Set the value of a key, inserting a new entry in key order if the key
+    /// is not present yet
+    pub fn set(
+        &mut self,
+        entries: &mut [u8],
+        key: &[u8],
+        value: &[u8],
+    ) -> Result<(), ProgramError> {
+        let key_length = u8::try_from(key.len())
+            .ok()
+            .filter(|&length| length > 0)
+            .ok_or(ProgramError::InvalidArgument)?;
+        let value_length = u32::try_from(value.len()).map_err(|_| ProgramError::InvalidArgument)?;
+        let used = self.used_length() as usize;
+        let (range, _) = find_entry(
+            entries
+                .get(..used)
+                .ok_or(ProgramError::InvalidAccountData)?,
+            key,
+        )?;
+        let entry_length = Self::ENTRY_HEADER_LEN
+            .saturating_add(key.len())
+            .saturating_add(value.len());
+        let new_used = used
+            .saturating_sub(range.len())
+            .checked_add(entry_length)
+            .filter(|&new_used| new_used <= entries.len())
+            .ok_or(RecordError::KeyValueTooSmall)?;
+        let entry_end = range.start.saturating_add(entry_length);
+
+        entries.copy_within(range.end..used, entry_end);
+        let entry = &mut entries[range.start..entry_end];
+        entry[0] = key_length;
+        entry[1..Self::ENTRY_HEADER_LEN].copy_from_slice(&value_length.to_le_bytes());
+        let (entry_key, entry_value) = entry[Self::ENTRY_HEADER_LEN..].split_at_mut(key.len());
+        entry_key.copy_from_slice(key);
+        entry_value.copy_from_slice(value);
+        if new_used < used {
+            entries[new_used..used].fill(0);
+        }
+        self.used_length = (new_used as u32).to_le_bytes();
+        Ok(())
+    }
+
+    /// Delete the entry of a key, failing if the key is not present
+    pub fn delete(&mut self, entries: &mut [u8], key: &[u8]) -> Result<(), ProgramError> {
+        let used = self.used_length() as usize;
+        let (range, found) = find_entry(
+            entries
+                .get(..used)
+                .ok_or(ProgramError::InvalidAccountData)?,
+            key,
+        )?;
+        if !found {
+            return Err(RecordError::KeyNotFound.into());
+        }
+        let new_used = used.saturating_sub(range.len());
+        entries.copy_within(range.end..used, range.start);
+        entries[new_used..used].fill(0);
+        self.used_length = (new_used as u32).to_le_bytes();
+        Ok(())
+    }
+}
+
+/// Parses the entry at the start of the input, returning its key, its value
+/// and its total length
+fn parse_entry(input: &[u8]) -> Option<(&[u8], &[u8], usize)> {
+    let (&key_length, rest) = input.split_first()?;
+    let (value_length, rest) = rest.split_first_chunk::<4>()?;
+    let value_length = u32::from_le_bytes(*value_length) as usize;
+    let (key, rest) = rest.split_at_checked(key_length as usize)?;
+    let value = rest.get(..value_length)?;
+    let length = KeyValueHeader::ENTRY_HEADER_LEN
+        .checked_add(key.len())?
+        .checked_add(value.len())?;
+    Some((key, value, length))
+}
+
+/// Finds the byte range of the entry of a key, or the empty range where it
+/// should be inserted if it is not present
+fn find_entry(entries: &[u8], key: &[u8]) -> Result<(Range<usize>, bool), ProgramError> {
+    let mut offset = 0;
+    while offset < entries.len() {
+        let (entry_key, _, length) =
+            parse_entry(&entries[offset..]).ok_or(ProgramError::InvalidAccountData)?;
+        let end = offset.saturating_add(length);
+        match entry_key.cmp(key) {
+            std::cmp::Ordering::Less => offset = end,
+            std::cmp::Ordering::Equal => return Ok((offset..end, true)),
+            std::cmp::Ordering::Greater => break,
+        }
+    }
+    Ok((offset..offset, false))
+}
+
+/// Read-only view over the account data of a key-value record
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct KeyValueMap<'a> {
+    entries: &'a [u8],
+}
+
+impl<'a> KeyValueMap<'a> {
+    /// Unpack the full account data of a key-value record
+    pub fn unpack(account_data: &'a [u8]) -> Result<Self, ProgramError> {
+        let (header, entries) = mode_payload(account_data, RecordMode::KeyValue)?
+            .split_at_checked(KeyValueHeader::LEN)
+            .ok_or(ProgramError::InvalidAccountData)?;
+        let header = bytemuck::try_from_bytes::<KeyValueHeader>(header)
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let entries = entries
+            .get(..header.used_length() as usize)
+            .ok_or(ProgramError::InvalidAccountData)?;
+        let mut offset = 0;
+        while offset < entries.len() {
+            let (_, _, length) =
+                parse_entry(&entries[offset..]).ok_or(ProgramError::InvalidAccountData)?;
+            offset = offset.saturating_add(length);
+        }
+        Ok(Self { entries })
+    }
+
+    /// Look up the value of a key
+    pub fn get(&self, key: &[u8]) -> Option<&'a [u8]> {
+        self.iter()
+            .find(|(entry_key, _)| *entry_key == key)
+            .map(|(_, value)| value)
+    }
+
+    /// Iterate over the entries, sorted by key
+    pub fn iter(&self) -> impl Iterator<Item = (&'a [u8], &'a [u8])> {
+        let mut entries = self.entries;
+        std::iter::from_fn(move || {
+            // every entry was checked to be well-formed in unpack
+            let (key, value, length) = parse_entry(entries)?;
+            entries = &entries[length..];
+            Some((key, value))
+        })
+    }
+
+    /// Number of entries
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// Number of bytes used by the entries
+    pub fn used_length(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the map has no entries
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Account registering a name for an authority, pointing at a record
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct NameRecord {
+    /// Always `NameRecord::ACCOUNT_TYPE`, distinct from any record version
+    pub account_type: u8,
+
+    /// The authority the name is registered for, allowed to repoint and
+    /// release it
+    pub authority: Address,
+
+    /// The record the name points at
+    pub record: Address,
+}
+
+impl NameRecord {
+    /// First byte of name accounts
+    pub const ACCOUNT_TYPE: u8 = u8::MAX;
+
+    /// Length of a name account
+    pub const LEN: usize = 65;
+
+    /// Maximum length of a name in bytes, the maximum length of a seed
+    pub const MAX_NAME_LEN: usize = 32;
+
+    /// Unpack the data of a name account
+    pub fn unpack(account_data: &[u8]) -> Result<&Self, ProgramError> {
+        bytemuck::try_from_bytes::<Self>(account_data)
+            .ok()
+            .filter(|name_record| name_record.account_type == Self::ACCOUNT_TYPE)
+            .ok_or(ProgramError::InvalidAccountData)
+    }
+}
+
 #[cfg(test)]
 pub(crate) mod tests {
     use {super::*, solana_program_error::ProgramError};
@@ -58,6 +999,276 @@ pub(crate) mod tests {
         );
     }
 
+    #[test]
+    fn serialize_data_v2() {
+        let record_data = RecordDataV2 {
+            version: RecordDataV2::VERSION,
+            authority: TEST_ADDRESS,
+            mode: RecordMode::AppendOnly as u8,
+            high_water_mark: 8u64.to_le_bytes(),
+            extensions_length: [0; 4],
+            alignment: 1,
+        };
+        let mut expected = vec![RecordDataV2::VERSION];
+        expected.extend_from_slice(&TEST_ADDRESS.to_bytes());
+        expected.push(1);
+        expected.extend_from_slice(&8u64.to_le_bytes());
+        expected.extend_from_slice(&0u32.to_le_bytes());
+        expected.push(1);
+        assert_eq!(bytemuck::bytes_of(&record_data), expected);
+        assert_eq!(expected.len(), RecordDataV2::LEN);
+
+        let record_data = RecordDataV2::unpack(&expected).unwrap();
+        assert_eq!(record_data.mode().unwrap(), RecordMode::AppendOnly);
+        assert_eq!(record_data.high_water_mark(), 8);
+        assert_eq!(record_data.alignment(), 1);
+
+        // the v1 header type does not treat extended records as initialized
+        let header =
+            bytemuck::try_from_bytes::<RecordData>(&expected[..RecordData::WRITABLE_START_INDEX])
+                .unwrap();
+        assert!(!header.is_initialized());
+        assert_eq!(
+            RecordData::writable_start_index(&expected).unwrap(),
+            RecordDataV2::LEN
+        );
+
+        let header = RecordHeader::unpack(&expected).unwrap();
+        assert_eq!(header, RecordHeader::V2(record_data));
+        assert_eq!(header.version(), RecordDataV2::VERSION);
+        assert_eq!(header.authority(), &TEST_ADDRESS);
+        assert_eq!(header.mode().unwrap(), RecordMode::AppendOnly);
+
+        // v1 records keep their payload right after the header
+        let mut expected = bytemuck::bytes_of(&TEST_RECORD_DATA).to_vec();
+        expected.extend_from_slice(&TEST_BYTES);
+        assert_eq!(
+            RecordData::writable_start_index(&expected).unwrap(),
+            RecordData::WRITABLE_START_INDEX
+        );
+        let header = RecordHeader::unpack(&expected).unwrap();
+        assert_eq!(header, RecordHeader::V1(&TEST_RECORD_DATA));
+        assert_eq!(header.mode().unwrap(), RecordMode::Standard);
+
+        expected[0] = 0;
+        assert_eq!(
+            RecordHeader::unpack(&expected).unwrap_err(),
+            RecordError::UninitializedRecord.into()
+        );
+        expected[0] = 3;
+        assert_eq!(
+            RecordHeader::unpack(&expected).unwrap_err(),
+            ProgramError::InvalidAccountData
+        );
+    }
+
+    #[test]
+    fn aligned_payload_views() {
+        assert_eq!(RecordDataV2::padding_for_alignment(1).unwrap(), 0);
+        assert_eq!(RecordDataV2::padding_for_alignment(8).unwrap(), 1);
+        assert_eq!(RecordDataV2::padding_for_alignment(64).unwrap(), 17);
+        assert_eq!(
+            RecordDataV2::padding_for_alignment(3).unwrap_err(),
+            ProgramError::InvalidArgument
+        );
+        assert_eq!(
+            RecordDataV2::padding_for_alignment(128).unwrap_err(),
+            ProgramError::InvalidArgument
+        );
+
+        // back the account data with u64s so it is 8-byte aligned, like
+        // account data passed to programs
+        let mut buffer = [0u64; 8];
+        let account_data: &mut [u8] = bytemuck::cast_slice_mut(&mut buffer);
+        let header = RecordDataV2 {
+            version: RecordDataV2::VERSION,
+            authority: TEST_ADDRESS,
+            mode: RecordMode::Standard as u8,
+            high_water_mark: [0; 8],
+            extensions_length: 1u32.to_le_bytes(),
+            alignment: 8,
+        };
+        account_data[..RecordDataV2::LEN].copy_from_slice(bytemuck::bytes_of(&header));
+        *payload_as_mut::<[u64; 2]>(account_data).unwrap() = [1, 2];
+        assert_eq!(*payload_as::<[u64; 2]>(account_data).unwrap(), [1, 2]);
+        assert_eq!(&account_data[48..56], &1u64.to_le_bytes());
+        assert_eq!(
+            payload_as::<[u64; 4]>(account_data).unwrap_err(),
+            ProgramError::AccountDataTooSmall
+        );
+
+        // without padding, the payload is misaligned
+        account_data[42..46].copy_from_slice(&0u32.to_le_bytes());
+        assert_eq!(
+            payload_as::<u64>(account_data).unwrap_err(),
+            ProgramError::InvalidAccountData
+        );
+    }
+
+    #[test]
+    fn record_account_validates_once() {
+        let mut account_data = bytemuck::bytes_of(&RecordDataV2 {
+            version: RecordDataV2::VERSION,
+            authority: TEST_ADDRESS,
+            mode: RecordMode::AppendOnly as u8,
+            high_water_mark: [0; 8],
+            extensions_length: 4u32.to_le_bytes(),
+            alignment: 1,
+        })
+        .to_vec();
+        account_data.extend_from_slice(&[0; 4]);
+        account_data.extend_from_slice(&TEST_BYTES);
+
+        let mut record = RecordAccount::unpack(account_data.as_mut_slice()).unwrap();
+        assert_eq!(record.authority(), &TEST_ADDRESS);
+        assert_eq!(record.mode().unwrap(), RecordMode::AppendOnly);
+        assert_eq!(record.extensions(), &[0; 4]);
+        assert_eq!(record.payload(), &TEST_BYTES);
+        *record.authority_mut() = Address::new_from_array([1; 32]);
+        record.header_v2_mut().unwrap().set_high_water_mark(4);
+        record.payload_mut()[0] = 0;
+        let record = RecordAccount::unpack(account_data.as_slice()).unwrap();
+        assert_eq!(record.authority(), &Address::new_from_array([1; 32]));
+        assert_eq!(record.payload()[..2], [0, 42]);
+
+        // the header keeps the version checked in unpack, even if the version
+        // byte is overwritten afterwards
+        let mut record = RecordAccount::unpack(account_data.as_mut_slice()).unwrap();
+        record.data_mut()[0] = 0xff;
+        assert!(matches!(record.header(), RecordHeader::V2(_)));
+        assert_eq!(record.mode().unwrap(), RecordMode::AppendOnly);
+        assert!(record.header_v2_mut().is_some());
+        account_data[0] = RecordDataV2::VERSION;
+
+        let mut v1_data = bytemuck::bytes_of(&TEST_RECORD_DATA).to_vec();
+        v1_data.extend_from_slice(&TEST_BYTES);
+        let mut record = RecordAccount::unpack(v1_data.as_mut_slice()).unwrap();
+        assert!(record.extensions().is_empty());
+        assert!(record.header_v2_mut().is_none());
+        assert_eq!(record.payload(), &TEST_BYTES);
+
+        assert_eq!(
+            RecordAccount::unpack(&account_data[..RecordDataV2::LEN]).unwrap_err(),
+            ProgramError::InvalidAccountData
+        );
+        assert_eq!(
+            RecordAccount::unpack(&[0u8; RecordDataV2::LEN][..]).unwrap_err(),
+            RecordError::UninitializedRecord.into()
+        );
+        assert_eq!(
+            RecordAccount::unpack(&v1_data[..8]).unwrap_err(),
+            RecordError::HeaderTooSmall.into()
+        );
+    }
+
+    #[test]
+    fn typed_record_reads_unaligned_payload() {
+        let mut account_data = bytemuck::bytes_of(&TEST_RECORD_DATA).to_vec();
+        account_data.extend_from_slice(&0x0102_0304_0506_0708u64.to_le_bytes());
+        let record = TypedRecord::<u64>::unpack(&account_data).unwrap();
+        assert_eq!(record.header.authority(), &TEST_ADDRESS);
+        assert_eq!(record.read(), 0x0102_0304_0506_0708);
+        assert_eq!(
+            TypedRecord::<[u64; 2]>::unpack(&account_data).unwrap_err(),
+            ProgramError::AccountDataTooSmall
+        );
+    }
+
+    #[test]
+    fn ring_buffer_iterates_chronologically() {
+        let entry_size = 2;
+        let capacity = 3;
+        let mut account_data = bytemuck::bytes_of(&RecordDataV2 {
+            version: RecordDataV2::VERSION,
+            authority: TEST_ADDRESS,
+            mode: RecordMode::RingBuffer as u8,
+            high_water_mark: [0; 8],
+            extensions_length: [0; 4],
+            alignment: 1,
+        })
+        .to_vec();
+        let mut header = RingBufferHeader::new(entry_size, capacity);
+        let mut entries = vec![0u8; (entry_size * capacity) as usize];
+
+        let mut data = account_data.clone();
+        data.extend_from_slice(bytemuck::bytes_of(&header));
+        data.extend_from_slice(&entries);
+        let ring_buffer = RingBuffer::unpack(&data).unwrap();
+        assert!(ring_buffer.is_empty());
+        assert_eq!(ring_buffer.iter().count(), 0);
+
+        for i in 1..=4u8 {
+            header.push(&mut entries, &[i; 2]).unwrap();
+        }
+        assert_eq!(
+            header.push(&mut entries, &[5; 3]).unwrap_err(),
+            RecordError::EntrySizeMismatch.into()
+        );
+        assert_eq!(header.head(), 1);
+        assert_eq!(header.count(), 3);
+        assert_eq!(entries, [4, 4, 2, 2, 3, 3]);
+
+        account_data.extend_from_slice(bytemuck::bytes_of(&header));
+        account_data.extend_from_slice(&entries);
+        let ring_buffer = RingBuffer::unpack(&account_data).unwrap();
+        assert_eq!(ring_buffer.len(), 3);
+        assert_eq!(
+            ring_buffer.iter().collect::<Vec<_>>(),
+            vec![&[2, 2], &[3, 3], &[4, 4]]
+        );
+    }
+
+    #[test]
+    fn key_value_set_and_delete() {
+        let mut account_data = bytemuck::bytes_of(&RecordDataV2 {
+            version: RecordDataV2::VERSION,
+            authority: TEST_ADDRESS,
+            mode: RecordMode::KeyValue as u8,
+            high_water_mark: [0; 8],
+            extensions_length: [0; 4],
+            alignment: 1,
+        })
+        .to_vec();
+        let mut header = KeyValueHeader {
+            used_length: [0; 4],
+        };
+        let mut entries = vec![0u8; 40];
+
+        header.set(&mut entries, b"b", b"22").unwrap();
+        header.set(&mut entries, b"c", b"3").unwrap();
+        header.set(&mut entries, b"a", b"1").unwrap();
+        header.set(&mut entries, b"b", b"2").unwrap();
+        assert_eq!(header.used_length(), 21);
+        assert_eq!(
+            header.set(&mut entries, b"d", &[4; 14]).unwrap_err(),
+            RecordError::KeyValueTooSmall.into()
+        );
+        assert_eq!(
+            header.set(&mut entries, b"", b"0").unwrap_err(),
+            ProgramError::InvalidArgument
+        );
+
+        header.delete(&mut entries, b"c").unwrap();
+        assert_eq!(
+            header.delete(&mut entries, b"c").unwrap_err(),
+            RecordError::KeyNotFound.into()
+        );
+        assert_eq!(header.used_length(), 14);
+        assert!(entries[14..].iter().all(|&byte| byte == 0));
+
+        account_data.extend_from_slice(bytemuck::bytes_of(&header));
+        account_data.extend_from_slice(&entries);
+        let map = KeyValueMap::unpack(&account_data).unwrap();
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get(b"a"), Some(&b"1"[..]));
+        assert_eq!(map.get(b"b"), Some(&b"2"[..]));
+        assert_eq!(map.get(b"c"), None);
+        assert_eq!(
+            map.iter().collect::<Vec<_>>(),
+            vec![(&b"a"[..], &b"1"[..]), (&b"b"[..], &b"2"[..])]
+        );
+    }
+
     #[test]
     fn deserialize_invalid_slice() {
         let mut expected = vec![TEST_VERSION];
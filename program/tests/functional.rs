@@ -1,12 +1,29 @@
 use {
-    mollusk_svm::{result::Check, Mollusk},
+    bytemuck::{Pod, Zeroable},
+    ed25519_dalek::{Signer, SigningKey},
+    mollusk_svm::{
+        result::{Check, InstructionResult},
+        Mollusk,
+    },
     solana_account::Account,
     solana_address::Address,
     solana_instruction::{AccountMeta, Instruction},
     solana_program_error::ProgramError,
     solana_rent::Rent,
+    solana_sha256_hasher::hash,
     solana_system_interface::instruction as system_instruction,
-    spl_record::{error::RecordError, id, instruction, state::RecordData},
+    spl_record::{
+        error::RecordError,
+        extension, get_content_address, get_name_address, id, instruction,
+        merkle::{self, MerkleTree},
+        resolve_name,
+        state::{
+            KeyValueHeader, KeyValueMap, Manifest, ManifestHeader, MerkleHeader, NameRecord,
+            RecordAccount, RecordData, RecordDataV2, RecordMode, RingBuffer, RingBufferHeader,
+            ShardEntry, TypedRecord,
+        },
+        write_field, NAME_SEED,
+    },
 };
 
 fn initialize_instructions(
@@ -31,6 +48,26 @@ fn initialize_instructions(
     ]
 }
 
+fn initialize_with_mode_instructions(
+    payer: &Address,
+    authority: &Address,
+    account: &Address,
+    mode: RecordMode,
+    data_length: usize,
+) -> [Instruction; 2] {
+    let account_length = RecordDataV2::LEN.checked_add(data_length).unwrap();
+    [
+        system_instruction::create_account(
+            payer,
+            account,
+            1.max(Rent::default().minimum_balance(account_length)),
+            account_length as u64,
+            &id(),
+        ),
+        instruction::initialize_with_mode(account, authority, mode),
+    ]
+}
+
 #[test]
 fn initialize_success() {
     let mollusk = Mollusk::new(&id(), "spl_record");
@@ -139,7 +176,9 @@ fn initialize_twice_fail() {
         [Check::success()],
         [Check::success()],
         [Check::success()],
-        [Check::err(ProgramError::AccountAlreadyInitialized)],
+        [Check::err(ProgramError::Custom(
+            RecordError::AlreadyInitialized as u32,
+        ))],
     ];
     mollusk.process_and_validate_instruction_chain(
         ixs.iter()
@@ -554,6 +593,102 @@ fn set_authority_fail_unsigned() {
     );
 }
 
+fn initialized_record_account(authority: &Address, data: &[u8]) -> Account {
+    let account_data = [RecordData::CURRENT_VERSION]
+        .into_iter()
+        .chain(authority.to_bytes())
+        .chain(data.iter().copied())
+        .collect::<Vec<_>>();
+    Account {
+        lamports: Rent::default().minimum_balance(account_data.len()),
+        data: account_data,
+        owner: id(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn set_authority_checked_success() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let authority = Address::new_unique();
+    let new_authority = Address::new_unique();
+    let account = Address::new_unique();
+    let data = &[111u8; 8];
+    let expected_data = [RecordData::CURRENT_VERSION]
+        .into_iter()
+        .chain(new_authority.to_bytes())
+        .chain(*data)
+        .collect::<Vec<_>>();
+    mollusk.process_and_validate_instruction(
+        &instruction::set_authority_checked(&account, &authority, &new_authority),
+        &[
+            (account, initialized_record_account(&authority, data)),
+            (authority, Account::default()),
+            (new_authority, Account::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&account).data(&expected_data).build(),
+        ],
+    );
+}
+
+#[test]
+fn set_authority_checked_fail_new_authority_unsigned() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let authority = Address::new_unique();
+    let new_authority = Address::new_unique();
+    let account = Address::new_unique();
+    let mut ix = instruction::set_authority_checked(&account, &authority, &new_authority);
+    ix.accounts[2].is_signer = false;
+    mollusk.process_and_validate_instruction(
+        &ix,
+        &[
+            (account, initialized_record_account(&authority, &[111u8; 8])),
+            (authority, Account::default()),
+            (new_authority, Account::default()),
+        ],
+        &[Check::err(ProgramError::MissingRequiredSignature)],
+    );
+}
+
+#[test]
+fn set_authority_checked_fail_record_address() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    mollusk.process_and_validate_instruction(
+        &instruction::set_authority_checked(&account, &authority, &account),
+        &[
+            (account, initialized_record_account(&authority, &[111u8; 8])),
+            (authority, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            RecordError::InvalidAuthority as u32,
+        ))],
+    );
+}
+
+#[test]
+fn set_authority_checked_fail_system_program() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let (system_program, system_program_account) =
+        mollusk_svm::program::keyed_account_for_system_program();
+    mollusk.process_and_validate_instruction(
+        &instruction::set_authority_checked(&account, &authority, &system_program),
+        &[
+            (account, initialized_record_account(&authority, &[111u8; 8])),
+            (authority, Account::default()),
+            (system_program, system_program_account),
+        ],
+        &[Check::err(ProgramError::Custom(
+            RecordError::InvalidAuthority as u32,
+        ))],
+    );
+}
+
 #[test]
 fn reallocate_success() {
     let mollusk = Mollusk::new(&id(), "spl_record");
@@ -623,6 +758,35 @@ fn reallocate_success() {
     );
 }
 
+#[test]
+fn reallocate_fail_overflow() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let mut ixs = initialize_instructions(&payer, &authority, &account, &[1u8; 8]).to_vec();
+    ixs.push(instruction::reallocate(&account, &authority, u64::MAX));
+    process_chain(
+        &mollusk,
+        &ixs,
+        &[Check::err(ProgramError::Custom(
+            RecordError::Overflow as u32,
+        ))],
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (account, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+}
+
 #[test]
 fn reallocate_fail_wrong_authority() {
     let mollusk = Mollusk::new(&id(), "spl_record");
@@ -730,3 +894,4800 @@ fn reallocate_fail_unsigned() {
         ],
     );
 }
+
+#[test]
+fn write_if_equal_success() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let data = &[111u8; 8];
+    let mut ixs = initialize_instructions(&payer, &authority, &account, data).to_vec();
+    ixs.push(instruction::write_if_equal(
+        &account,
+        &authority,
+        0,
+        &[111u8; 2],
+        4,
+        &[200u8; 4],
+    ));
+    let expected_data = [RecordData::CURRENT_VERSION]
+        .into_iter()
+        .chain(authority.to_bytes())
+        .chain([111u8; 4])
+        .chain([200u8; 4])
+        .collect::<Vec<_>>();
+    let checks = [
+        [Check::success()],
+        [Check::success()],
+        [Check::success()],
+        [Check::account(&account).data(&expected_data).build()],
+    ];
+    mollusk.process_and_validate_instruction_chain(
+        ixs.iter()
+            .zip(checks.iter().map(|c| c.as_ref()))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (account, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn write_if_equal_fail_mismatch() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let data = &[111u8; 8];
+    let mut ixs = initialize_instructions(&payer, &authority, &account, data).to_vec();
+    ixs.push(instruction::write_if_equal(
+        &account,
+        &authority,
+        0,
+        &[112u8; 2],
+        4,
+        &[200u8; 4],
+    ));
+    let checks = [
+        [Check::success()],
+        [Check::success()],
+        [Check::success()],
+        [Check::err(ProgramError::Custom(
+            RecordError::ExpectedDataMismatch as u32,
+        ))],
+    ];
+    mollusk.process_and_validate_instruction_chain(
+        ixs.iter()
+            .zip(checks.iter().map(|c| c.as_ref()))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (account, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn write_if_equal_fail_wrong_authority() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let wrong_authority = Address::new_unique();
+    let account = Address::new_unique();
+    let data = &[111u8; 8];
+    let mut ixs = initialize_instructions(&payer, &authority, &account, data).to_vec();
+    ixs.push(instruction::write_if_equal(
+        &account,
+        &wrong_authority,
+        0,
+        &[111u8; 2],
+        4,
+        &[200u8; 4],
+    ));
+    let checks = [
+        [Check::success()],
+        [Check::success()],
+        [Check::success()],
+        [Check::err(ProgramError::Custom(
+            RecordError::IncorrectAuthority as u32,
+        ))],
+    ];
+    mollusk.process_and_validate_instruction_chain(
+        ixs.iter()
+            .zip(checks.iter().map(|c| c.as_ref()))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (wrong_authority, Account::default()),
+            (account, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn write_multiple_success() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let index_account = Address::new_unique();
+    let payload_account = Address::new_unique();
+    let data = &[111u8; 8];
+    let mut ixs = initialize_instructions(&payer, &authority, &index_account, data).to_vec();
+    ixs.extend(initialize_instructions(
+        &payer,
+        &authority,
+        &payload_account,
+        data,
+    ));
+    ixs.push(instruction::write_multiple(
+        &[index_account, payload_account],
+        &authority,
+        vec![
+            instruction::RecordWrite {
+                record_index: 0,
+                offset: 0,
+                data: &[1u8; 4],
+            },
+            instruction::RecordWrite {
+                record_index: 1,
+                offset: 4,
+                data: &[2u8; 4],
+            },
+        ],
+    ));
+    let expected_index_data = [RecordData::CURRENT_VERSION]
+        .into_iter()
+        .chain(authority.to_bytes())
+        .chain([1u8; 4])
+        .chain([111u8; 4])
+        .collect::<Vec<_>>();
+    let expected_payload_data = [RecordData::CURRENT_VERSION]
+        .into_iter()
+        .chain(authority.to_bytes())
+        .chain([111u8; 4])
+        .chain([2u8; 4])
+        .collect::<Vec<_>>();
+    let checks = [
+        vec![Check::success()],
+        vec![Check::success()],
+        vec![Check::success()],
+        vec![Check::success()],
+        vec![Check::success()],
+        vec![Check::success()],
+        vec![
+            Check::success(),
+            Check::account(&index_account)
+                .data(&expected_index_data)
+                .build(),
+            Check::account(&payload_account)
+                .data(&expected_payload_data)
+                .build(),
+        ],
+    ];
+    mollusk.process_and_validate_instruction_chain(
+        ixs.iter()
+            .zip(checks.iter().map(|c| c.as_ref()))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (index_account, Account::default()),
+            (payload_account, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn write_multiple_fail_wrong_authority() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let other_authority = Address::new_unique();
+    let index_account = Address::new_unique();
+    let payload_account = Address::new_unique();
+    let data = &[111u8; 8];
+    let mut ixs = initialize_instructions(&payer, &authority, &index_account, data).to_vec();
+    ixs.extend(initialize_instructions(
+        &payer,
+        &other_authority,
+        &payload_account,
+        data,
+    ));
+    ixs.push(instruction::write_multiple(
+        &[index_account, payload_account],
+        &authority,
+        vec![
+            instruction::RecordWrite {
+                record_index: 0,
+                offset: 0,
+                data: &[1u8; 4],
+            },
+            instruction::RecordWrite {
+                record_index: 1,
+                offset: 4,
+                data: &[2u8; 4],
+            },
+        ],
+    ));
+    let checks = [
+        [Check::success()],
+        [Check::success()],
+        [Check::success()],
+        [Check::success()],
+        [Check::success()],
+        [Check::success()],
+        [Check::err(ProgramError::Custom(
+            RecordError::IncorrectAuthority as u32,
+        ))],
+    ];
+    mollusk.process_and_validate_instruction_chain(
+        ixs.iter()
+            .zip(checks.iter().map(|c| c.as_ref()))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (other_authority, Account::default()),
+            (index_account, Account::default()),
+            (payload_account, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn write_multiple_fail_out_of_bounds() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let index_account = Address::new_unique();
+    let payload_account = Address::new_unique();
+    let data = &[111u8; 8];
+    let mut ixs = initialize_instructions(&payer, &authority, &index_account, data).to_vec();
+    ixs.extend(initialize_instructions(
+        &payer,
+        &authority,
+        &payload_account,
+        data,
+    ));
+    ixs.push(instruction::write_multiple(
+        &[index_account, payload_account],
+        &authority,
+        vec![
+            instruction::RecordWrite {
+                record_index: 0,
+                offset: 0,
+                data: &[1u8; 4],
+            },
+            instruction::RecordWrite {
+                record_index: 1,
+                offset: 6,
+                data: &[2u8; 4],
+            },
+        ],
+    ));
+    let checks = [
+        [Check::success()],
+        [Check::success()],
+        [Check::success()],
+        [Check::success()],
+        [Check::success()],
+        [Check::success()],
+        [Check::err(ProgramError::Custom(
+            RecordError::WriteOutOfBounds as u32,
+        ))],
+    ];
+    mollusk.process_and_validate_instruction_chain(
+        ixs.iter()
+            .zip(checks.iter().map(|c| c.as_ref()))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (index_account, Account::default()),
+            (payload_account, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn write_multiple_fail_unsigned() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let data = &[111u8; 8];
+    let mut ixs = initialize_instructions(&payer, &authority, &account, data).to_vec();
+    ixs.push(Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new_readonly(authority, false),
+            AccountMeta::new(account, false),
+        ],
+        data: instruction::RecordInstruction::WriteMultiple {
+            writes: vec![instruction::RecordWrite {
+                record_index: 0,
+                offset: 0,
+                data,
+            }],
+        }
+        .pack(),
+    });
+    let checks = [
+        [Check::success()],
+        [Check::success()],
+        [Check::success()],
+        [Check::err(ProgramError::MissingRequiredSignature)],
+    ];
+    mollusk.process_and_validate_instruction_chain(
+        ixs.iter()
+            .zip(checks.iter().map(|c| c.as_ref()))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (account, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn commit_success() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let recipient = Address::new_unique();
+    let account = Address::new_unique();
+    let buffer = Address::new_unique();
+    let data = &[111u8; 8];
+    let buffer_data = &[222u8; 16];
+    let buffer_length = std::mem::size_of::<RecordData>()
+        .checked_add(buffer_data.len())
+        .unwrap();
+    let additional_lamports_needed = Rent::default().minimum_balance(buffer_length);
+    let mut ixs = initialize_instructions(&payer, &authority, &account, data).to_vec();
+    ixs.extend(initialize_instructions(
+        &payer,
+        &authority,
+        &buffer,
+        buffer_data,
+    ));
+    ixs.push(system_instruction::transfer(
+        &payer,
+        &account,
+        additional_lamports_needed,
+    ));
+    ixs.push(instruction::commit(
+        &account,
+        &buffer,
+        &authority,
+        &recipient,
+        Some(hash(buffer_data).to_bytes()),
+    ));
+    let expected_data = [RecordData::CURRENT_VERSION]
+        .into_iter()
+        .chain(authority.to_bytes())
+        .chain(*buffer_data)
+        .collect::<Vec<_>>();
+    let checks = [
+        vec![Check::success()],
+        vec![Check::success()],
+        vec![Check::success()],
+        vec![Check::success()],
+        vec![Check::success()],
+        vec![Check::success()],
+        vec![Check::success()],
+        vec![
+            Check::success(),
+            Check::account(&account).data(&expected_data).build(),
+            Check::account(&buffer).lamports(0).build(),
+            Check::account(&recipient)
+                .lamports(Rent::default().minimum_balance(buffer_length))
+                .build(),
+        ],
+    ];
+    mollusk.process_and_validate_instruction_chain(
+        ixs.iter()
+            .zip(checks.iter().map(|c| c.as_ref()))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (recipient, Account::default()),
+            (account, Account::default()),
+            (buffer, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn commit_fail_hash_mismatch() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let recipient = Address::new_unique();
+    let account = Address::new_unique();
+    let buffer = Address::new_unique();
+    let data = &[111u8; 8];
+    let buffer_data = &[222u8; 8];
+    let mut ixs = initialize_instructions(&payer, &authority, &account, data).to_vec();
+    ixs.extend(initialize_instructions(
+        &payer,
+        &authority,
+        &buffer,
+        buffer_data,
+    ));
+    ixs.push(instruction::commit(
+        &account,
+        &buffer,
+        &authority,
+        &recipient,
+        Some(hash(data).to_bytes()),
+    ));
+    let checks = [
+        [Check::success()],
+        [Check::success()],
+        [Check::success()],
+        [Check::success()],
+        [Check::success()],
+        [Check::success()],
+        [Check::err(ProgramError::Custom(
+            RecordError::HashMismatch as u32,
+        ))],
+    ];
+    mollusk.process_and_validate_instruction_chain(
+        ixs.iter()
+            .zip(checks.iter().map(|c| c.as_ref()))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (recipient, Account::default()),
+            (account, Account::default()),
+            (buffer, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn commit_fail_wrong_authority() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let buffer_authority = Address::new_unique();
+    let recipient = Address::new_unique();
+    let account = Address::new_unique();
+    let buffer = Address::new_unique();
+    let data = &[111u8; 8];
+    let buffer_data = &[222u8; 8];
+    let mut ixs = initialize_instructions(&payer, &authority, &account, data).to_vec();
+    ixs.extend(initialize_instructions(
+        &payer,
+        &buffer_authority,
+        &buffer,
+        buffer_data,
+    ));
+    ixs.push(instruction::commit(
+        &account, &buffer, &authority, &recipient, None,
+    ));
+    let checks = [
+        [Check::success()],
+        [Check::success()],
+        [Check::success()],
+        [Check::success()],
+        [Check::success()],
+        [Check::success()],
+        [Check::err(ProgramError::Custom(
+            RecordError::IncorrectAuthority as u32,
+        ))],
+    ];
+    mollusk.process_and_validate_instruction_chain(
+        ixs.iter()
+            .zip(checks.iter().map(|c| c.as_ref()))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (buffer_authority, Account::default()),
+            (recipient, Account::default()),
+            (account, Account::default()),
+            (buffer, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn initialize_with_mode_success() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let ixs =
+        initialize_with_mode_instructions(&payer, &authority, &account, RecordMode::AppendOnly, 8);
+    let expected_data = [RecordDataV2::VERSION]
+        .into_iter()
+        .chain(authority.to_bytes())
+        .chain([RecordMode::AppendOnly as u8])
+        .chain(0u64.to_le_bytes())
+        .chain(0u32.to_le_bytes())
+        .chain([1])
+        .chain([0u8; 8])
+        .collect::<Vec<_>>();
+    let checks = [
+        [Check::success()],
+        [Check::account(&account).data(&expected_data).build()],
+    ];
+    mollusk.process_and_validate_instruction_chain(
+        ixs.iter()
+            .zip(checks.iter().map(|c| c.as_ref()))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (account, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn write_after_extensions_success() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let header = RecordDataV2 {
+        version: RecordDataV2::VERSION,
+        authority,
+        mode: RecordMode::Standard as u8,
+        high_water_mark: [0; 8],
+        extensions_length: 8u32.to_le_bytes(),
+        alignment: 1,
+    };
+    let account_data = bytemuck::bytes_of(&header)
+        .iter()
+        .copied()
+        .chain([0u8; 8])
+        .chain([0u8; 8])
+        .collect::<Vec<_>>();
+    let record = RecordAccount::unpack(account_data.as_slice()).unwrap();
+    assert!(extension::get_extension_types(record.extensions())
+        .unwrap()
+        .is_empty());
+    let expected_data = bytemuck::bytes_of(&header)
+        .iter()
+        .copied()
+        .chain([0u8; 8])
+        .chain([1u8; 4])
+        .chain([0u8; 4])
+        .collect::<Vec<_>>();
+    mollusk.process_and_validate_instruction(
+        &instruction::write(&account, &authority, 0, &[1u8; 4]),
+        &[
+            (authority, Account::default()),
+            (
+                account,
+                Account {
+                    lamports: Rent::default().minimum_balance(account_data.len()),
+                    data: account_data,
+                    owner: id(),
+                    ..Default::default()
+                },
+            ),
+        ],
+        &[
+            Check::success(),
+            Check::account(&account).data(&expected_data).build(),
+        ],
+    );
+}
+
+#[test]
+fn write_fail_wrong_owner() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let account_data = bytemuck::bytes_of(&RecordData {
+        version: RecordData::CURRENT_VERSION,
+        authority,
+    })
+    .iter()
+    .copied()
+    .chain([0u8; 8])
+    .collect::<Vec<_>>();
+    mollusk.process_and_validate_instruction(
+        &instruction::write(&account, &authority, 0, &[1u8; 4]),
+        &[
+            (authority, Account::default()),
+            (
+                account,
+                Account {
+                    lamports: Rent::default().minimum_balance(account_data.len()),
+                    data: account_data,
+                    owner: Address::new_unique(),
+                    ..Default::default()
+                },
+            ),
+        ],
+        &[Check::err(ProgramError::Custom(
+            RecordError::IncorrectOwner as u32,
+        ))],
+    );
+}
+
+#[test]
+fn write_fail_out_of_bounds() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    mollusk.process_and_validate_instruction(
+        &instruction::write(&account, &authority, 6, &[1u8; 4]),
+        &[
+            (account, initialized_record_account(&authority, &[111u8; 8])),
+            (authority, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            RecordError::WriteOutOfBounds as u32,
+        ))],
+    );
+}
+
+#[test]
+fn write_fail_not_writable() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let data = &[111u8; 8];
+    let mut ixs = initialize_instructions(&payer, &authority, &account, data).to_vec();
+    let mut write = instruction::write(&account, &authority, 0, &[1u8; 4]);
+    write.accounts[0].is_writable = false;
+    ixs.push(write);
+    let checks = [
+        [Check::success()],
+        [Check::success()],
+        [Check::success()],
+        [Check::err(ProgramError::Custom(
+            RecordError::AccountNotWritable as u32,
+        ))],
+    ];
+    mollusk.process_and_validate_instruction_chain(
+        ixs.iter()
+            .zip(checks.iter().map(|c| c.as_ref()))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (account, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn initialize_fail_wrong_owner() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let account_length = RecordData::WRITABLE_START_INDEX + 8;
+    mollusk.process_and_validate_instruction(
+        &instruction::initialize(&account, &authority),
+        &[
+            (
+                account,
+                Account {
+                    lamports: Rent::default().minimum_balance(account_length),
+                    data: vec![0; account_length],
+                    owner: Address::new_unique(),
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            RecordError::IncorrectOwner as u32,
+        ))],
+    );
+}
+
+fn uninitialized_record_account(data_length: usize) -> Account {
+    let account_length = RecordData::WRITABLE_START_INDEX
+        .checked_add(data_length)
+        .unwrap();
+    Account {
+        lamports: Rent::default().minimum_balance(account_length),
+        data: vec![0; account_length],
+        owner: id(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn initialize_checked_success() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let expected_data = [RecordData::CURRENT_VERSION]
+        .into_iter()
+        .chain(authority.to_bytes())
+        .chain([0u8; 8])
+        .collect::<Vec<_>>();
+    mollusk.process_and_validate_instruction(
+        &instruction::initialize_checked(&account, &authority),
+        &[
+            (account, uninitialized_record_account(8)),
+            (authority, Account::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&account).data(&expected_data).build(),
+        ],
+    );
+}
+
+#[test]
+fn initialize_checked_record_signer_success() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let mut ix = instruction::initialize_checked(&account, &authority);
+    ix.accounts[0].is_signer = true;
+    ix.accounts[1].is_signer = false;
+    mollusk.process_and_validate_instruction(
+        &ix,
+        &[
+            (account, uninitialized_record_account(8)),
+            (authority, Account::default()),
+        ],
+        &[Check::success()],
+    );
+}
+
+#[test]
+fn initialize_checked_fail_unsigned() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let mut ix = instruction::initialize_checked(&account, &authority);
+    ix.accounts[1].is_signer = false;
+    mollusk.process_and_validate_instruction(
+        &ix,
+        &[
+            (account, uninitialized_record_account(8)),
+            (authority, Account::default()),
+        ],
+        &[Check::err(ProgramError::MissingRequiredSignature)],
+    );
+}
+
+#[test]
+fn initialize_checked_fail_default_authority() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let authority = Address::default();
+    let account = Address::new_unique();
+    let mut ix = instruction::initialize_checked(&account, &authority);
+    ix.accounts[0].is_signer = true;
+    ix.accounts[1].is_signer = false;
+    mollusk.process_and_validate_instruction(
+        &ix,
+        &[
+            (account, uninitialized_record_account(8)),
+            (authority, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            RecordError::InvalidAuthority as u32,
+        ))],
+    );
+}
+
+#[test]
+fn initialize_checked_fail_not_rent_exempt() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    mollusk.process_and_validate_instruction(
+        &instruction::initialize_checked(&account, &authority),
+        &[
+            (
+                account,
+                Account {
+                    lamports: 1,
+                    ..uninitialized_record_account(8)
+                },
+            ),
+            (authority, Account::default()),
+        ],
+        &[Check::err(ProgramError::AccountNotRentExempt)],
+    );
+}
+
+#[test]
+fn close_account_fail_duplicate_destination() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let data = &[111u8; 8];
+    let mut ixs = initialize_instructions(&payer, &authority, &account, data).to_vec();
+    ixs.push(instruction::close_account(&account, &authority, &account));
+    let checks = [
+        [Check::success()],
+        [Check::success()],
+        [Check::success()],
+        [Check::err(ProgramError::Custom(
+            RecordError::DuplicateAccount as u32,
+        ))],
+    ];
+    mollusk.process_and_validate_instruction_chain(
+        ixs.iter()
+            .zip(checks.iter().map(|c| c.as_ref()))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (account, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn commit_fail_duplicate_buffer() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let recipient = Address::new_unique();
+    let account = Address::new_unique();
+    let data = &[111u8; 8];
+    let mut ixs = initialize_instructions(&payer, &authority, &account, data).to_vec();
+    ixs.push(instruction::commit(
+        &account, &account, &authority, &recipient, None,
+    ));
+    let checks = [
+        [Check::success()],
+        [Check::success()],
+        [Check::success()],
+        [Check::err(ProgramError::Custom(
+            RecordError::DuplicateAccount as u32,
+        ))],
+    ];
+    mollusk.process_and_validate_instruction_chain(
+        ixs.iter()
+            .zip(checks.iter().map(|c| c.as_ref()))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (recipient, Account::default()),
+            (account, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn commit_fail_record_as_destination() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let buffer = Address::new_unique();
+    let data = &[111u8; 8];
+    let mut ixs = initialize_instructions(&payer, &authority, &account, data).to_vec();
+    ixs.extend(initialize_instructions(&payer, &authority, &buffer, data));
+    ixs.push(instruction::commit(
+        &account, &buffer, &authority, &account, None,
+    ));
+    process_chain(
+        &mollusk,
+        &ixs,
+        &[Check::err(ProgramError::Custom(
+            RecordError::DuplicateAccount as u32,
+        ))],
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (account, Account::default()),
+            (buffer, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn commit_fail_buffer_wrong_owner() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let authority = Address::new_unique();
+    let recipient = Address::new_unique();
+    let account = Address::new_unique();
+    let buffer = Address::new_unique();
+    mollusk.process_and_validate_instruction(
+        &instruction::commit(&account, &buffer, &authority, &recipient, None),
+        &[
+            (account, initialized_record_account(&authority, &[0u8; 8])),
+            (
+                buffer,
+                Account {
+                    owner: Address::new_unique(),
+                    ..initialized_record_account(&authority, &[0u8; 8])
+                },
+            ),
+            (authority, Account::default()),
+            (recipient, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            RecordError::IncorrectOwner as u32,
+        ))],
+    );
+}
+
+#[test]
+fn commit_fail_destination_not_writable() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let authority = Address::new_unique();
+    let recipient = Address::new_unique();
+    let account = Address::new_unique();
+    let buffer = Address::new_unique();
+    let mut commit = instruction::commit(&account, &buffer, &authority, &recipient, None);
+    commit.accounts[3].is_writable = false;
+    mollusk.process_and_validate_instruction(
+        &commit,
+        &[
+            (account, initialized_record_account(&authority, &[0u8; 8])),
+            (buffer, initialized_record_account(&authority, &[1u8; 8])),
+            (authority, Account::default()),
+            (recipient, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            RecordError::AccountNotWritable as u32,
+        ))],
+    );
+}
+
+#[test]
+fn write_multiple_fail_duplicate_record() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let data = &[111u8; 8];
+    let mut ixs = initialize_instructions(&payer, &authority, &account, data).to_vec();
+    ixs.push(instruction::write_multiple(
+        &[account, account],
+        &authority,
+        vec![
+            instruction::RecordWrite {
+                record_index: 0,
+                offset: 0,
+                data: &[1u8; 4],
+            },
+            instruction::RecordWrite {
+                record_index: 1,
+                offset: 0,
+                data: &[2u8; 4],
+            },
+        ],
+    ));
+    let checks = [
+        [Check::success()],
+        [Check::success()],
+        [Check::success()],
+        [Check::err(ProgramError::Custom(
+            RecordError::DuplicateAccount as u32,
+        ))],
+    ];
+    mollusk.process_and_validate_instruction_chain(
+        ixs.iter()
+            .zip(checks.iter().map(|c| c.as_ref()))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (account, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn write_multiple_fail_unwritten_record() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let unwritten_account = Address::new_unique();
+    mollusk.process_and_validate_instruction(
+        &instruction::write_multiple(
+            &[account, unwritten_account],
+            &authority,
+            vec![instruction::RecordWrite {
+                record_index: 1,
+                offset: 0,
+                data: &[1u8; 4],
+            }],
+        ),
+        &[
+            (authority, Account::default()),
+            (account, initialized_record_account(&authority, &[0u8; 8])),
+            (
+                unwritten_account,
+                initialized_record_account(&authority, &[0u8; 8]),
+            ),
+        ],
+        &[Check::err(ProgramError::Custom(
+            RecordError::UnwrittenRecord as u32,
+        ))],
+    );
+}
+
+#[test]
+fn append_only_write_success() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let mut ixs =
+        initialize_with_mode_instructions(&payer, &authority, &account, RecordMode::AppendOnly, 8)
+            .to_vec();
+    ixs.push(instruction::write(&account, &authority, 0, &[1u8; 4]));
+    ixs.push(instruction::write(&account, &authority, 4, &[2u8; 4]));
+    let expected_data = [RecordDataV2::VERSION]
+        .into_iter()
+        .chain(authority.to_bytes())
+        .chain([RecordMode::AppendOnly as u8])
+        .chain(8u64.to_le_bytes())
+        .chain(0u32.to_le_bytes())
+        .chain([1])
+        .chain([1u8; 4])
+        .chain([2u8; 4])
+        .collect::<Vec<_>>();
+    let checks = [
+        [Check::success()],
+        [Check::success()],
+        [Check::success()],
+        [Check::account(&account).data(&expected_data).build()],
+    ];
+    mollusk.process_and_validate_instruction_chain(
+        ixs.iter()
+            .zip(checks.iter().map(|c| c.as_ref()))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (account, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn append_only_write_fail_rewrite() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let mut ixs =
+        initialize_with_mode_instructions(&payer, &authority, &account, RecordMode::AppendOnly, 8)
+            .to_vec();
+    ixs.push(instruction::write(&account, &authority, 0, &[1u8; 4]));
+    ixs.push(instruction::write(&account, &authority, 2, &[2u8; 4]));
+    let checks = [
+        [Check::success()],
+        [Check::success()],
+        [Check::success()],
+        [Check::err(ProgramError::Custom(
+            RecordError::AppendOnlyViolation as u32,
+        ))],
+    ];
+    mollusk.process_and_validate_instruction_chain(
+        ixs.iter()
+            .zip(checks.iter().map(|c| c.as_ref()))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (account, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn append_only_reallocate_fail_shrink() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let mut ixs =
+        initialize_with_mode_instructions(&payer, &authority, &account, RecordMode::AppendOnly, 8)
+            .to_vec();
+    ixs.push(instruction::reallocate(&account, &authority, 16));
+    ixs.push(instruction::reallocate(&account, &authority, 4));
+    let checks = [
+        [Check::success()],
+        [Check::success()],
+        [Check::success()],
+        [Check::err(ProgramError::Custom(
+            RecordError::AppendOnlyViolation as u32,
+        ))],
+    ];
+    mollusk.process_and_validate_instruction_chain(
+        ixs.iter()
+            .zip(checks.iter().map(|c| c.as_ref()))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (account, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn ring_buffer_push_entry_success() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let entry_size = 4u32;
+    let account_length = RecordDataV2::LEN + RingBufferHeader::LEN + 12;
+    let mut ixs = vec![
+        system_instruction::create_account(
+            &payer,
+            &account,
+            Rent::default().minimum_balance(account_length),
+            account_length as u64,
+            &id(),
+        ),
+        instruction::initialize_ring_buffer(&account, &authority, entry_size),
+    ];
+    for i in 1..=4u8 {
+        ixs.push(instruction::push_entry(&account, &authority, &[i; 4]));
+    }
+    let expected_data = [RecordDataV2::VERSION]
+        .into_iter()
+        .chain(authority.to_bytes())
+        .chain([RecordMode::RingBuffer as u8])
+        .chain(0u64.to_le_bytes())
+        .chain(0u32.to_le_bytes())
+        .chain([1])
+        .chain(
+            bytemuck::bytes_of(&RingBufferHeader {
+                entry_size: entry_size.to_le_bytes(),
+                capacity: 3u32.to_le_bytes(),
+                head: 1u32.to_le_bytes(),
+                count: 3u32.to_le_bytes(),
+            })
+            .iter()
+            .copied(),
+        )
+        .chain([4u8; 4])
+        .chain([2u8; 4])
+        .chain([3u8; 4])
+        .collect::<Vec<_>>();
+    let checks = [
+        [Check::success()],
+        [Check::success()],
+        [Check::success()],
+        [Check::success()],
+        [Check::success()],
+        [Check::account(&account).data(&expected_data).build()],
+    ];
+    let result = mollusk.process_and_validate_instruction_chain(
+        ixs.iter()
+            .zip(checks.iter().map(|c| c.as_ref()))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (account, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+    let account_data = &result.get_account(&account).unwrap().data;
+    let ring_buffer = RingBuffer::unpack(account_data).unwrap();
+    assert_eq!(
+        ring_buffer.iter().collect::<Vec<_>>(),
+        vec![&[2u8; 4], &[3u8; 4], &[4u8; 4]]
+    );
+}
+
+#[test]
+fn ring_buffer_push_entry_fail_wrong_size() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let account_length = RecordDataV2::LEN + RingBufferHeader::LEN + 12;
+    let ixs = [
+        system_instruction::create_account(
+            &payer,
+            &account,
+            Rent::default().minimum_balance(account_length),
+            account_length as u64,
+            &id(),
+        ),
+        instruction::initialize_ring_buffer(&account, &authority, 4),
+        instruction::push_entry(&account, &authority, &[1u8; 5]),
+    ];
+    let checks = [
+        [Check::success()],
+        [Check::success()],
+        [Check::err(ProgramError::Custom(
+            RecordError::EntrySizeMismatch as u32,
+        ))],
+    ];
+    mollusk.process_and_validate_instruction_chain(
+        ixs.iter()
+            .zip(checks.iter().map(|c| c.as_ref()))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (account, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn ring_buffer_initialize_fail_zero_entry_size() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let account_length = RecordDataV2::LEN + RingBufferHeader::LEN + 12;
+    mollusk.process_and_validate_instruction(
+        &instruction::initialize_ring_buffer(&account, &authority, 0),
+        &[
+            (authority, Account::default()),
+            (
+                account,
+                Account {
+                    lamports: Rent::default().minimum_balance(account_length),
+                    data: vec![0; account_length],
+                    owner: id(),
+                    ..Default::default()
+                },
+            ),
+        ],
+        &[Check::err(ProgramError::Custom(
+            RecordError::InvalidEntrySize as u32,
+        ))],
+    );
+}
+
+#[test]
+fn ring_buffer_initialize_fail_too_small() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let account_length = RecordDataV2::LEN + RingBufferHeader::LEN + 3;
+    mollusk.process_and_validate_instruction(
+        &instruction::initialize_ring_buffer(&account, &authority, 4),
+        &[
+            (authority, Account::default()),
+            (
+                account,
+                Account {
+                    lamports: Rent::default().minimum_balance(account_length),
+                    data: vec![0; account_length],
+                    owner: id(),
+                    ..Default::default()
+                },
+            ),
+        ],
+        &[Check::err(ProgramError::Custom(
+            RecordError::RingBufferTooSmall as u32,
+        ))],
+    );
+}
+
+#[test]
+fn ring_buffer_write_fail() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let account_length = RecordDataV2::LEN + RingBufferHeader::LEN + 12;
+    let ixs = [
+        system_instruction::create_account(
+            &payer,
+            &account,
+            Rent::default().minimum_balance(account_length),
+            account_length as u64,
+            &id(),
+        ),
+        instruction::initialize_ring_buffer(&account, &authority, 4),
+        instruction::write(&account, &authority, 0, &[1u8; 4]),
+    ];
+    let checks = [
+        [Check::success()],
+        [Check::success()],
+        [Check::err(ProgramError::Custom(
+            RecordError::InvalidRecordMode as u32,
+        ))],
+    ];
+    mollusk.process_and_validate_instruction_chain(
+        ixs.iter()
+            .zip(checks.iter().map(|c| c.as_ref()))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (account, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+}
+
+fn initialize_merkle_accumulator_instructions(
+    payer: &Address,
+    authority: &Address,
+    account: &Address,
+    depth: u8,
+) -> [Instruction; 2] {
+    let account_length = (depth as usize)
+        .checked_mul(merkle::NODE_LEN)
+        .and_then(|frontier_len| frontier_len.checked_add(MerkleHeader::LEN))
+        .and_then(|payload_len| payload_len.checked_add(RecordDataV2::LEN))
+        .unwrap();
+    [
+        system_instruction::create_account(
+            payer,
+            account,
+            Rent::default().minimum_balance(account_length),
+            account_length as u64,
+            &id(),
+        ),
+        instruction::initialize_merkle_accumulator(account, authority, depth),
+    ]
+}
+
+#[test]
+fn merkle_accumulator_initialize_fail_too_small() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let account_length = RecordDataV2::LEN + MerkleHeader::LEN + merkle::NODE_LEN;
+    mollusk.process_and_validate_instruction(
+        &instruction::initialize_merkle_accumulator(&account, &authority, 2),
+        &[
+            (authority, Account::default()),
+            (
+                account,
+                Account {
+                    lamports: Rent::default().minimum_balance(account_length),
+                    data: vec![0; account_length],
+                    owner: id(),
+                    ..Default::default()
+                },
+            ),
+        ],
+        &[Check::err(ProgramError::Custom(
+            RecordError::MerkleAccumulatorTooSmall as u32,
+        ))],
+    );
+}
+
+#[test]
+fn merkle_accumulator_append_and_verify_success() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let depth = 3;
+    let mut tree = MerkleTree::new(depth);
+    let mut ixs =
+        initialize_merkle_accumulator_instructions(&payer, &authority, &account, depth).to_vec();
+    for i in 0..5u8 {
+        let leaf = merkle::hash_leaf(&[i]);
+        tree.push(leaf);
+        ixs.push(instruction::append_leaf(&account, &authority, leaf));
+    }
+    let proof = tree.proof(3).unwrap();
+    ixs.push(instruction::verify_leaf(
+        &account,
+        tree.leaves()[3],
+        3,
+        &proof,
+    ));
+    let checks = ixs.iter().map(|_| [Check::success()]).collect::<Vec<_>>();
+    let result = mollusk.process_and_validate_instruction_chain(
+        ixs.iter()
+            .zip(checks.iter().map(|c| c.as_ref()))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (account, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+    let account_data = &result.get_account(&account).unwrap().data;
+    let header = MerkleHeader::unpack(account_data).unwrap();
+    assert_eq!(header.root, tree.root());
+    assert_eq!(header.leaf_count(), 5);
+    assert_eq!(header.depth, depth);
+}
+
+#[test]
+fn merkle_accumulator_verify_fail_invalid_proof() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let depth = 2;
+    let mut tree = MerkleTree::new(depth);
+    let mut ixs =
+        initialize_merkle_accumulator_instructions(&payer, &authority, &account, depth).to_vec();
+    for i in 0..3u8 {
+        let leaf = merkle::hash_leaf(&[i]);
+        tree.push(leaf);
+        ixs.push(instruction::append_leaf(&account, &authority, leaf));
+    }
+    let proof = tree.proof(1).unwrap();
+    ixs.push(instruction::verify_leaf(
+        &account,
+        merkle::hash_leaf(&[9]),
+        1,
+        &proof,
+    ));
+    let mut checks = ixs[1..]
+        .iter()
+        .map(|_| [Check::success()])
+        .collect::<Vec<_>>();
+    checks.push([Check::err(ProgramError::Custom(
+        RecordError::InvalidMerkleProof as u32,
+    ))]);
+    mollusk.process_and_validate_instruction_chain(
+        ixs.iter()
+            .zip(checks.iter().map(|c| c.as_ref()))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (account, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn merkle_accumulator_append_fail_full() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let [create, initialize] =
+        initialize_merkle_accumulator_instructions(&payer, &authority, &account, 1);
+    let ixs = [
+        create,
+        initialize,
+        instruction::append_leaf(&account, &authority, [1; 32]),
+        instruction::append_leaf(&account, &authority, [2; 32]),
+        instruction::append_leaf(&account, &authority, [3; 32]),
+    ];
+    let checks = [
+        [Check::success()],
+        [Check::success()],
+        [Check::success()],
+        [Check::success()],
+        [Check::err(ProgramError::Custom(
+            RecordError::MerkleTreeFull as u32,
+        ))],
+    ];
+    mollusk.process_and_validate_instruction_chain(
+        ixs.iter()
+            .zip(checks.iter().map(|c| c.as_ref()))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (account, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn manifest_append_and_replace_shard_success() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let manifest = Address::new_unique();
+    let shards = [
+        (Address::new_unique(), [1u8; 16]),
+        (Address::new_unique(), [2u8; 16]),
+        (Address::new_unique(), [3u8; 16]),
+    ];
+    let mut ixs = initialize_with_mode_instructions(
+        &payer,
+        &authority,
+        &manifest,
+        RecordMode::Manifest,
+        ManifestHeader::LEN + 2 * ShardEntry::LEN,
+    )
+    .to_vec();
+    for (shard, data) in &shards {
+        ixs.extend(initialize_instructions(&payer, &authority, shard, data));
+    }
+    ixs.push(instruction::append_shard(
+        &manifest,
+        &shards[0].0,
+        &authority,
+        hash(&shards[0].1).to_bytes(),
+    ));
+    ixs.push(instruction::append_shard(
+        &manifest,
+        &shards[1].0,
+        &authority,
+        hash(&shards[1].1).to_bytes(),
+    ));
+    ixs.push(instruction::replace_shard(
+        &manifest,
+        &shards[2].0,
+        &authority,
+        1,
+        hash(&shards[2].1).to_bytes(),
+    ));
+    let checks = ixs.iter().map(|_| [Check::success()]).collect::<Vec<_>>();
+    let mut accounts = vec![
+        (
+            payer,
+            Account {
+                lamports: 1_000_000_000,
+                ..Default::default()
+            },
+        ),
+        (authority, Account::default()),
+        (manifest, Account::default()),
+        mollusk_svm::program::keyed_account_for_system_program(),
+    ];
+    accounts.extend(shards.iter().map(|(shard, _)| (*shard, Account::default())));
+    let result = mollusk.process_and_validate_instruction_chain(
+        ixs.iter()
+            .zip(checks.iter().map(|c| c.as_ref()))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        &accounts,
+    );
+
+    let manifest_data = &result.get_account(&manifest).unwrap().data;
+    let manifest = Manifest::unpack(manifest_data).unwrap();
+    assert_eq!(manifest.shards.len(), 2);
+    assert_eq!(manifest.payload_len(), 32);
+    let shard_accounts = [shards[0].0, shards[2].0]
+        .map(|shard| (shard, result.get_account(&shard).unwrap().data.clone()));
+    let payload = manifest
+        .reassemble(
+            shard_accounts
+                .iter()
+                .map(|(shard, data)| (shard, data.as_slice())),
+        )
+        .unwrap();
+    assert_eq!(payload, [[1u8; 16], [3u8; 16]].concat());
+
+    let err = manifest
+        .reassemble(
+            shard_accounts
+                .iter()
+                .rev()
+                .map(|(shard, data)| (shard, data.as_slice())),
+        )
+        .unwrap_err();
+    assert_eq!(err, RecordError::ShardMismatch.into());
+}
+
+#[test]
+fn manifest_append_shard_fail_wrong_authority() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let shard_authority = Address::new_unique();
+    let manifest = Address::new_unique();
+    let shard = Address::new_unique();
+    let data = [1u8; 16];
+    let mut ixs = initialize_with_mode_instructions(
+        &payer,
+        &authority,
+        &manifest,
+        RecordMode::Manifest,
+        ManifestHeader::LEN + ShardEntry::LEN,
+    )
+    .to_vec();
+    ixs.extend(initialize_instructions(
+        &payer,
+        &shard_authority,
+        &shard,
+        &data,
+    ));
+    ixs.push(instruction::append_shard(
+        &manifest,
+        &shard,
+        &authority,
+        hash(&data).to_bytes(),
+    ));
+    let mut checks = ixs[1..]
+        .iter()
+        .map(|_| [Check::success()])
+        .collect::<Vec<_>>();
+    checks.push([Check::err(ProgramError::Custom(
+        RecordError::IncorrectAuthority as u32,
+    ))]);
+    mollusk.process_and_validate_instruction_chain(
+        ixs.iter()
+            .zip(checks.iter().map(|c| c.as_ref()))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (shard_authority, Account::default()),
+            (manifest, Account::default()),
+            (shard, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn manifest_append_shard_fail_no_room() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let manifest = Address::new_unique();
+    let shard = Address::new_unique();
+    let data = [1u8; 16];
+    let mut ixs = initialize_with_mode_instructions(
+        &payer,
+        &authority,
+        &manifest,
+        RecordMode::Manifest,
+        ManifestHeader::LEN,
+    )
+    .to_vec();
+    ixs.extend(initialize_instructions(&payer, &authority, &shard, &data));
+    ixs.push(instruction::append_shard(
+        &manifest,
+        &shard,
+        &authority,
+        hash(&data).to_bytes(),
+    ));
+    let mut checks = ixs[1..]
+        .iter()
+        .map(|_| [Check::success()])
+        .collect::<Vec<_>>();
+    checks.push([Check::err(ProgramError::Custom(
+        RecordError::ManifestTooSmall as u32,
+    ))]);
+    mollusk.process_and_validate_instruction_chain(
+        ixs.iter()
+            .zip(checks.iter().map(|c| c.as_ref()))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (manifest, Account::default()),
+            (shard, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn manifest_replace_shard_fail_index_out_of_range() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let manifest = Address::new_unique();
+    let shard = Address::new_unique();
+    let data = [1u8; 16];
+    let mut ixs = initialize_with_mode_instructions(
+        &payer,
+        &authority,
+        &manifest,
+        RecordMode::Manifest,
+        ManifestHeader::LEN + ShardEntry::LEN,
+    )
+    .to_vec();
+    ixs.extend(initialize_instructions(&payer, &authority, &shard, &data));
+    ixs.push(instruction::replace_shard(
+        &manifest,
+        &shard,
+        &authority,
+        0,
+        hash(&data).to_bytes(),
+    ));
+    process_chain(
+        &mollusk,
+        &ixs,
+        &[Check::err(ProgramError::Custom(
+            RecordError::ShardIndexOutOfRange as u32,
+        ))],
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (manifest, Account::default()),
+            (shard, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn manifest_append_shard_fail_wrong_owner() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let authority = Address::new_unique();
+    let manifest = Address::new_unique();
+    let shard = Address::new_unique();
+    mollusk.process_and_validate_instruction(
+        &instruction::append_shard(&manifest, &shard, &authority, [0u8; 32]),
+        &[
+            (
+                manifest,
+                Account {
+                    owner: Address::new_unique(),
+                    ..initialized_record_account(&authority, &[0u8; 8])
+                },
+            ),
+            (shard, initialized_record_account(&authority, &[1u8; 8])),
+            (authority, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            RecordError::IncorrectOwner as u32,
+        ))],
+    );
+}
+
+#[test]
+fn manifest_append_shard_fail_not_writable() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let authority = Address::new_unique();
+    let manifest = Address::new_unique();
+    let shard = Address::new_unique();
+    let mut append = instruction::append_shard(&manifest, &shard, &authority, [0u8; 32]);
+    append.accounts[0].is_writable = false;
+    mollusk.process_and_validate_instruction(
+        &append,
+        &[
+            (manifest, initialized_record_account(&authority, &[0u8; 8])),
+            (shard, initialized_record_account(&authority, &[1u8; 8])),
+            (authority, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            RecordError::AccountNotWritable as u32,
+        ))],
+    );
+}
+
+#[test]
+fn manifest_append_shard_fail_manifest_as_shard() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let authority = Address::new_unique();
+    let manifest = Address::new_unique();
+    mollusk.process_and_validate_instruction(
+        &instruction::append_shard(&manifest, &manifest, &authority, [0u8; 32]),
+        &[
+            (manifest, initialized_record_account(&authority, &[0u8; 8])),
+            (authority, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            RecordError::DuplicateAccount as u32,
+        ))],
+    );
+}
+
+#[test]
+fn manifest_replace_shard_fail_shard_wrong_owner() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let authority = Address::new_unique();
+    let manifest = Address::new_unique();
+    let shard = Address::new_unique();
+    mollusk.process_and_validate_instruction(
+        &instruction::replace_shard(&manifest, &shard, &authority, 0, [0u8; 32]),
+        &[
+            (manifest, initialized_record_account(&authority, &[0u8; 8])),
+            (
+                shard,
+                Account {
+                    owner: Address::new_unique(),
+                    ..initialized_record_account(&authority, &[0u8; 8])
+                },
+            ),
+            (authority, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            RecordError::IncorrectOwner as u32,
+        ))],
+    );
+}
+
+#[test]
+fn manifest_replace_shard_fail_not_writable() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let authority = Address::new_unique();
+    let manifest = Address::new_unique();
+    let shard = Address::new_unique();
+    let mut replace = instruction::replace_shard(&manifest, &shard, &authority, 0, [0u8; 32]);
+    replace.accounts[0].is_writable = false;
+    mollusk.process_and_validate_instruction(
+        &replace,
+        &[
+            (manifest, initialized_record_account(&authority, &[0u8; 8])),
+            (shard, initialized_record_account(&authority, &[1u8; 8])),
+            (authority, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            RecordError::AccountNotWritable as u32,
+        ))],
+    );
+}
+
+#[test]
+fn manifest_replace_shard_fail_manifest_as_shard() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let authority = Address::new_unique();
+    let manifest = Address::new_unique();
+    mollusk.process_and_validate_instruction(
+        &instruction::replace_shard(&manifest, &manifest, &authority, 0, [0u8; 32]),
+        &[
+            (manifest, initialized_record_account(&authority, &[0u8; 8])),
+            (authority, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            RecordError::DuplicateAccount as u32,
+        ))],
+    );
+}
+
+#[test]
+fn manifest_reallocate_fail_shrink_below_entries() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let manifest = Address::new_unique();
+    let shard = Address::new_unique();
+    let data = [1u8; 16];
+    let mut ixs = initialize_with_mode_instructions(
+        &payer,
+        &authority,
+        &manifest,
+        RecordMode::Manifest,
+        ManifestHeader::LEN + ShardEntry::LEN,
+    )
+    .to_vec();
+    ixs.extend(initialize_instructions(&payer, &authority, &shard, &data));
+    ixs.push(instruction::append_shard(
+        &manifest,
+        &shard,
+        &authority,
+        hash(&data).to_bytes(),
+    ));
+    ixs.push(instruction::reallocate(
+        &manifest,
+        &authority,
+        ManifestHeader::LEN as u64,
+    ));
+    process_chain(
+        &mollusk,
+        &ixs,
+        &[Check::err(ProgramError::Custom(
+            RecordError::ShrinkBelowEntries as u32,
+        ))],
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (manifest, Account::default()),
+            (shard, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn content_record_finalize_success() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let data = [7u8; 48];
+    let content_hash = hash(&data).to_bytes();
+    let account = get_content_address(&content_hash);
+    let ixs = [
+        instruction::initialize_content(&payer, &authority, content_hash, data.len() as u64),
+        instruction::write(&account, &authority, 0, &data[..24]),
+        instruction::write(&account, &authority, 24, &data[24..]),
+        instruction::finalize_content(&account, &authority, content_hash),
+        instruction::write(&account, &authority, 0, &[0u8; 8]),
+    ];
+    let expected_data = [RecordDataV2::VERSION]
+        .into_iter()
+        .chain(authority.to_bytes())
+        .chain([RecordMode::Content as u8])
+        .chain(0u64.to_le_bytes())
+        .chain(0u32.to_le_bytes())
+        .chain([1])
+        .chain(data)
+        .collect::<Vec<_>>();
+    let checks = [
+        [Check::success()],
+        [Check::success()],
+        [Check::success()],
+        [Check::account(&account).data(&expected_data).build()],
+        [Check::err(ProgramError::Custom(
+            RecordError::RecordFrozen as u32,
+        ))],
+    ];
+    mollusk.process_and_validate_instruction_chain(
+        ixs.iter()
+            .zip(checks.iter().map(|c| c.as_ref()))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (account, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn content_record_finalize_fail_hash_mismatch() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let data = [7u8; 48];
+    let content_hash = hash(&data).to_bytes();
+    let account = get_content_address(&content_hash);
+    let ixs = [
+        instruction::initialize_content(&payer, &authority, content_hash, data.len() as u64),
+        instruction::write(&account, &authority, 0, &data[..24]),
+        instruction::finalize_content(&account, &authority, content_hash),
+    ];
+    let checks = [
+        [Check::success()],
+        [Check::success()],
+        [Check::err(ProgramError::Custom(
+            RecordError::HashMismatch as u32,
+        ))],
+    ];
+    mollusk.process_and_validate_instruction_chain(
+        ixs.iter()
+            .zip(checks.iter().map(|c| c.as_ref()))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (account, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn content_record_initialize_fail_wrong_address() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let mut ix = instruction::initialize_content(&payer, &authority, [7u8; 32], 16);
+    ix.accounts[1] = AccountMeta::new(account, false);
+    mollusk.process_and_validate_instruction(
+        &ix,
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (account, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+        &[Check::err(ProgramError::InvalidSeeds)],
+    );
+}
+
+#[test]
+fn content_record_initialize_fail_unsigned_authority() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let content_hash = [7u8; 32];
+    let account = get_content_address(&content_hash);
+    let mut ix = instruction::initialize_content(&payer, &authority, content_hash, 16);
+    ix.accounts[2].is_signer = false;
+    mollusk.process_and_validate_instruction(
+        &ix,
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (account, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+        &[Check::err(ProgramError::MissingRequiredSignature)],
+    );
+}
+
+#[test]
+fn content_record_initialize_prefunded_success() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let content_hash = [7u8; 32];
+    let account = get_content_address(&content_hash);
+    let expected_data = [RecordDataV2::VERSION]
+        .into_iter()
+        .chain(authority.to_bytes())
+        .chain([RecordMode::ContentPending as u8])
+        .chain(0u64.to_le_bytes())
+        .chain(0u32.to_le_bytes())
+        .chain([1])
+        .chain([0u8; 16])
+        .collect::<Vec<_>>();
+    mollusk.process_and_validate_instruction(
+        &instruction::initialize_content(&payer, &authority, content_hash, 16),
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (
+                account,
+                Account {
+                    lamports: 1,
+                    ..Default::default()
+                },
+            ),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+        &[
+            Check::success(),
+            Check::account(&account)
+                .data(&expected_data)
+                .lamports(Rent::default().minimum_balance(expected_data.len()))
+                .owner(&spl_record::ID)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn name_register_repoint_release_success() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let record = Address::new_unique();
+    let new_record = Address::new_unique();
+    let receiver = Address::new_unique();
+    let name = "config";
+    let name_account = get_name_address(&authority, name).unwrap();
+    let mut ixs = initialize_instructions(&payer, &authority, &record, &[1u8; 8]).to_vec();
+    ixs.extend(initialize_instructions(
+        &payer,
+        &authority,
+        &new_record,
+        &[2u8; 8],
+    ));
+    ixs.push(instruction::register_name(&payer, &authority, &record, name).unwrap());
+    ixs.push(instruction::repoint_name(
+        &name_account,
+        &authority,
+        &new_record,
+    ));
+    let name_data = |record: &Address| {
+        bytemuck::bytes_of(&NameRecord {
+            account_type: NameRecord::ACCOUNT_TYPE,
+            authority,
+            record: *record,
+        })
+        .to_vec()
+    };
+    let registered_data = name_data(&record);
+    let repointed_data = name_data(&new_record);
+    let mut checks = ixs[2..]
+        .iter()
+        .map(|_| [Check::success()])
+        .collect::<Vec<_>>();
+    checks.push([Check::account(&name_account)
+        .data(&registered_data)
+        .owner(&spl_record::ID)
+        .build()]);
+    checks.push([Check::account(&name_account).data(&repointed_data).build()]);
+    let result = mollusk.process_and_validate_instruction_chain(
+        ixs.iter()
+            .zip(checks.iter().map(|c| c.as_ref()))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (record, Account::default()),
+            (new_record, Account::default()),
+            (name_account, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+    let name_account_data = result.get_account(&name_account).unwrap().clone();
+    assert_eq!(
+        resolve_name(&authority, name, |address| (*address == name_account)
+            .then_some(&name_account_data.data)),
+        Ok(new_record)
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction::release_name(&name_account, &authority, &receiver),
+        &[
+            (name_account, name_account_data.clone()),
+            (authority, Account::default()),
+            (receiver, Account::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&receiver)
+                .lamports(name_account_data.lamports)
+                .build(),
+            Check::account(&name_account).lamports(0).build(),
+        ],
+    );
+}
+
+fn name_record_account(authority: &Address, record: &Address) -> Account {
+    let data = bytemuck::bytes_of(&NameRecord {
+        account_type: NameRecord::ACCOUNT_TYPE,
+        authority: *authority,
+        record: *record,
+    })
+    .to_vec();
+    Account {
+        lamports: Rent::default().minimum_balance(data.len()),
+        data,
+        owner: id(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn name_release_fail_wrong_owner() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let authority = Address::new_unique();
+    let recipient = Address::new_unique();
+    let name_account = Address::new_unique();
+    mollusk.process_and_validate_instruction(
+        &instruction::release_name(&name_account, &authority, &recipient),
+        &[
+            (
+                name_account,
+                Account {
+                    owner: Address::new_unique(),
+                    ..name_record_account(&authority, &Address::new_unique())
+                },
+            ),
+            (authority, Account::default()),
+            (recipient, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            RecordError::IncorrectOwner as u32,
+        ))],
+    );
+}
+
+#[test]
+fn name_release_fail_not_writable() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let authority = Address::new_unique();
+    let recipient = Address::new_unique();
+    let name_account = Address::new_unique();
+    let mut release = instruction::release_name(&name_account, &authority, &recipient);
+    release.accounts[0].is_writable = false;
+    mollusk.process_and_validate_instruction(
+        &release,
+        &[
+            (
+                name_account,
+                name_record_account(&authority, &Address::new_unique()),
+            ),
+            (authority, Account::default()),
+            (recipient, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            RecordError::AccountNotWritable as u32,
+        ))],
+    );
+}
+
+#[test]
+fn name_release_fail_name_as_destination() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let authority = Address::new_unique();
+    let name_account = Address::new_unique();
+    mollusk.process_and_validate_instruction(
+        &instruction::release_name(&name_account, &authority, &name_account),
+        &[
+            (
+                name_account,
+                name_record_account(&authority, &Address::new_unique()),
+            ),
+            (authority, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            RecordError::DuplicateAccount as u32,
+        ))],
+    );
+}
+
+#[test]
+fn name_register_fail_empty_name() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let record = Address::new_unique();
+    // the builder refuses empty names, so derive the account directly
+    let (name_account, _) = Address::find_program_address(&[NAME_SEED, authority.as_ref()], &id());
+    let [create, initialize, write] =
+        initialize_instructions(&payer, &authority, &record, &[1u8; 8]);
+    let ixs = [
+        create,
+        initialize,
+        write,
+        Instruction {
+            program_id: id(),
+            accounts: vec![
+                AccountMeta::new(payer, true),
+                AccountMeta::new(name_account, false),
+                AccountMeta::new_readonly(authority, true),
+                AccountMeta::new_readonly(record, false),
+                AccountMeta::new_readonly(solana_system_interface::program::id(), false),
+            ],
+            data: instruction::RecordInstruction::RegisterName { name: "" }.pack(),
+        },
+    ];
+    let checks = [
+        [Check::success()],
+        [Check::success()],
+        [Check::success()],
+        [Check::err(ProgramError::Custom(
+            RecordError::InvalidName as u32,
+        ))],
+    ];
+    mollusk.process_and_validate_instruction_chain(
+        ixs.iter()
+            .zip(checks.iter().map(|c| c.as_ref()))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (record, Account::default()),
+            (name_account, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn name_register_prefunded_success() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let record = Address::new_unique();
+    let name = "config";
+    let name_account = get_name_address(&authority, name).unwrap();
+    let [create, initialize, write] =
+        initialize_instructions(&payer, &authority, &record, &[1u8; 8]);
+    let ixs = [
+        create,
+        initialize,
+        write,
+        instruction::register_name(&payer, &authority, &record, name).unwrap(),
+    ];
+    let name_data = bytemuck::bytes_of(&NameRecord {
+        account_type: NameRecord::ACCOUNT_TYPE,
+        authority,
+        record,
+    })
+    .to_vec();
+    let prefunded_lamports = Rent::default().minimum_balance(NameRecord::LEN) + 1;
+    let checks = [
+        [Check::success()],
+        [Check::success()],
+        [Check::success()],
+        [Check::account(&name_account)
+            .data(&name_data)
+            .lamports(prefunded_lamports)
+            .owner(&spl_record::ID)
+            .build()],
+    ];
+    mollusk.process_and_validate_instruction_chain(
+        ixs.iter()
+            .zip(checks.iter().map(|c| c.as_ref()))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (record, Account::default()),
+            (
+                name_account,
+                Account {
+                    lamports: prefunded_lamports,
+                    ..Default::default()
+                },
+            ),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn name_reinitialize_fail() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let attacker = Address::new_unique();
+    let record = Address::new_unique();
+    let name = "config";
+    let name_account = get_name_address(&authority, name).unwrap();
+    let mut ixs = initialize_instructions(&payer, &authority, &record, &[1u8; 8]).to_vec();
+    ixs.push(instruction::register_name(&payer, &authority, &record, name).unwrap());
+    let result = process_chain(
+        &mollusk,
+        &ixs,
+        &[Check::success()],
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (record, Account::default()),
+            (name_account, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+    let accounts = [
+        (
+            name_account,
+            result.get_account(&name_account).unwrap().clone(),
+        ),
+        (attacker, Account::default()),
+    ];
+    let already_initialized = [Check::err(ProgramError::Custom(
+        RecordError::AlreadyInitialized as u32,
+    ))];
+    mollusk.process_and_validate_instruction(
+        &instruction::initialize(&name_account, &attacker),
+        &accounts,
+        &already_initialized,
+    );
+    mollusk.process_and_validate_instruction(
+        &instruction::initialize_checked(&name_account, &attacker),
+        &accounts,
+        &already_initialized,
+    );
+    mollusk.process_and_validate_instruction(
+        &instruction::close_account(&name_account, &attacker, &attacker),
+        &accounts,
+        &[Check::err(ProgramError::InvalidAccountData)],
+    );
+}
+
+#[test]
+fn name_repoint_fail_wrong_authority() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let bad_authority = Address::new_unique();
+    let record = Address::new_unique();
+    let name = "config";
+    let name_account = get_name_address(&authority, name).unwrap();
+    let [create, initialize, write] =
+        initialize_instructions(&payer, &authority, &record, &[1u8; 8]);
+    let ixs = [
+        create,
+        initialize,
+        write,
+        instruction::register_name(&payer, &authority, &record, name).unwrap(),
+        instruction::repoint_name(&name_account, &bad_authority, &record),
+    ];
+    let checks = [
+        [Check::success()],
+        [Check::success()],
+        [Check::success()],
+        [Check::success()],
+        [Check::err(ProgramError::Custom(
+            RecordError::IncorrectAuthority as u32,
+        ))],
+    ];
+    mollusk.process_and_validate_instruction_chain(
+        ixs.iter()
+            .zip(checks.iter().map(|c| c.as_ref()))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (bad_authority, Account::default()),
+            (record, Account::default()),
+            (name_account, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn key_value_set_and_delete_key_success() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let mut ixs = initialize_with_mode_instructions(
+        &payer,
+        &authority,
+        &account,
+        RecordMode::KeyValue,
+        KeyValueHeader::LEN + 64,
+    )
+    .to_vec();
+    ixs.extend([
+        instruction::set_key(&account, &authority, b"owner", b"alice"),
+        instruction::set_key(&account, &authority, b"limit", &100u64.to_le_bytes()),
+        instruction::set_key(&account, &authority, b"name", b"pool"),
+        instruction::set_key(&account, &authority, b"owner", b"bob"),
+        instruction::delete_key(&account, &authority, b"name"),
+    ]);
+    let checks = ixs.iter().map(|_| [Check::success()]).collect::<Vec<_>>();
+    let result = mollusk.process_and_validate_instruction_chain(
+        ixs.iter()
+            .zip(checks.iter().map(|c| c.as_ref()))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (account, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+    let account_data = &result.get_account(&account).unwrap().data;
+    let map = KeyValueMap::unpack(account_data).unwrap();
+    assert_eq!(
+        map.iter().collect::<Vec<_>>(),
+        vec![
+            (&b"limit"[..], &100u64.to_le_bytes()[..]),
+            (&b"owner"[..], &b"bob"[..]),
+        ]
+    );
+    assert_eq!(map.get(b"owner"), Some(&b"bob"[..]));
+    assert_eq!(map.get(b"name"), None);
+}
+
+#[test]
+fn key_value_set_key_fail_no_room() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let [create, initialize] = initialize_with_mode_instructions(
+        &payer,
+        &authority,
+        &account,
+        RecordMode::KeyValue,
+        KeyValueHeader::LEN + 8,
+    );
+    let ixs = [
+        create,
+        initialize,
+        instruction::set_key(&account, &authority, b"key", b"value"),
+    ];
+    let checks = [
+        [Check::success()],
+        [Check::success()],
+        [Check::err(ProgramError::Custom(
+            RecordError::KeyValueTooSmall as u32,
+        ))],
+    ];
+    mollusk.process_and_validate_instruction_chain(
+        ixs.iter()
+            .zip(checks.iter().map(|c| c.as_ref()))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (account, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn key_value_reallocate_fail_shrink_below_entries() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let mut ixs = initialize_with_mode_instructions(
+        &payer,
+        &authority,
+        &account,
+        RecordMode::KeyValue,
+        KeyValueHeader::LEN + 32,
+    )
+    .to_vec();
+    ixs.push(instruction::set_key(&account, &authority, b"key", b"value"));
+    ixs.push(instruction::reallocate(
+        &account,
+        &authority,
+        KeyValueHeader::LEN as u64 + 8,
+    ));
+    process_chain(
+        &mollusk,
+        &ixs,
+        &[Check::err(ProgramError::Custom(
+            RecordError::ShrinkBelowEntries as u32,
+        ))],
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (account, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn key_value_delete_key_fail_not_found() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let [create, initialize] = initialize_with_mode_instructions(
+        &payer,
+        &authority,
+        &account,
+        RecordMode::KeyValue,
+        KeyValueHeader::LEN + 32,
+    );
+    let ixs = [
+        create,
+        initialize,
+        instruction::set_key(&account, &authority, b"key", b"value"),
+        instruction::delete_key(&account, &authority, b"other"),
+    ];
+    let checks = [
+        [Check::success()],
+        [Check::success()],
+        [Check::success()],
+        [Check::err(ProgramError::Custom(
+            RecordError::KeyNotFound as u32,
+        ))],
+    ];
+    mollusk.process_and_validate_instruction_chain(
+        ixs.iter()
+            .zip(checks.iter().map(|c| c.as_ref()))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (account, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn migrate_success() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let data = &[111u8; 8];
+    let header_growth = RecordDataV2::LEN - RecordData::WRITABLE_START_INDEX;
+    let mut ixs = initialize_instructions(&payer, &authority, &account, data).to_vec();
+    ixs.push(system_instruction::transfer(
+        &payer,
+        &account,
+        Rent::default().minimum_balance(header_growth),
+    ));
+    ixs.push(instruction::migrate(&account, &authority));
+    ixs.push(instruction::write(&account, &authority, 4, &[2u8; 4]));
+    let header = RecordDataV2 {
+        version: RecordDataV2::VERSION,
+        authority,
+        mode: RecordMode::Standard as u8,
+        high_water_mark: [0; 8],
+        extensions_length: [0; 4],
+        alignment: 1,
+    };
+    let migrated_data = bytemuck::bytes_of(&header)
+        .iter()
+        .copied()
+        .chain(*data)
+        .collect::<Vec<_>>();
+    let written_data = bytemuck::bytes_of(&header)
+        .iter()
+        .copied()
+        .chain([111u8; 4])
+        .chain([2u8; 4])
+        .collect::<Vec<_>>();
+    let checks = [
+        [Check::success()],
+        [Check::success()],
+        [Check::success()],
+        [Check::success()],
+        [Check::account(&account).data(&migrated_data).build()],
+        [Check::account(&account).data(&written_data).build()],
+    ];
+    mollusk.process_and_validate_instruction_chain(
+        ixs.iter()
+            .zip(checks.iter().map(|c| c.as_ref()))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (account, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn migrate_fail_already_migrated() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let mut ixs =
+        initialize_with_mode_instructions(&payer, &authority, &account, RecordMode::Standard, 8)
+            .to_vec();
+    ixs.push(instruction::migrate(&account, &authority));
+    let checks = [
+        [Check::success()],
+        [Check::success()],
+        [Check::err(ProgramError::Custom(
+            RecordError::AlreadyMigrated as u32,
+        ))],
+    ];
+    mollusk.process_and_validate_instruction_chain(
+        ixs.iter()
+            .zip(checks.iter().map(|c| c.as_ref()))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (account, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn migrate_fail_wrong_authority() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let wrong_authority = Address::new_unique();
+    let account = Address::new_unique();
+    let mut ixs = initialize_instructions(&payer, &authority, &account, &[111u8; 8]).to_vec();
+    ixs.push(instruction::migrate(&account, &wrong_authority));
+    let checks = [
+        [Check::success()],
+        [Check::success()],
+        [Check::success()],
+        [Check::err(ProgramError::Custom(
+            RecordError::IncorrectAuthority as u32,
+        ))],
+    ];
+    mollusk.process_and_validate_instruction_chain(
+        ixs.iter()
+            .zip(checks.iter().map(|c| c.as_ref()))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (wrong_authority, Account::default()),
+            (account, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn migrate_fail_wrong_owner() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    mollusk.process_and_validate_instruction(
+        &instruction::migrate(&account, &authority),
+        &[
+            (
+                account,
+                Account {
+                    owner: Address::new_unique(),
+                    ..initialized_record_account(&authority, &[0u8; 8])
+                },
+            ),
+            (authority, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            RecordError::IncorrectOwner as u32,
+        ))],
+    );
+}
+
+#[test]
+fn migrate_fail_not_writable() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let mut migrate = instruction::migrate(&account, &authority);
+    migrate.accounts[0].is_writable = false;
+    mollusk.process_and_validate_instruction(
+        &migrate,
+        &[
+            (account, initialized_record_account(&authority, &[0u8; 8])),
+            (authority, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            RecordError::AccountNotWritable as u32,
+        ))],
+    );
+}
+
+#[test]
+fn initialize_aligned_success() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let padding = RecordDataV2::padding_for_alignment(8).unwrap();
+    let account_length = RecordDataV2::LEN + padding + 16;
+    let ixs = [
+        system_instruction::create_account(
+            &payer,
+            &account,
+            Rent::default().minimum_balance(account_length),
+            account_length as u64,
+            &id(),
+        ),
+        instruction::initialize_aligned(&account, &authority, RecordMode::Standard, 8),
+        instruction::write(&account, &authority, 0, &7u64.to_le_bytes()),
+    ];
+    let header = RecordDataV2 {
+        version: RecordDataV2::VERSION,
+        authority,
+        mode: RecordMode::Standard as u8,
+        high_water_mark: [0; 8],
+        extensions_length: (padding as u32).to_le_bytes(),
+        alignment: 8,
+    };
+    let expected_data = bytemuck::bytes_of(&header)
+        .iter()
+        .copied()
+        .chain(vec![0u8; padding])
+        .chain(7u64.to_le_bytes())
+        .chain([0u8; 8])
+        .collect::<Vec<_>>();
+    assert_eq!(
+        RecordData::writable_start_index(&expected_data).unwrap(),
+        48
+    );
+    let checks = [
+        [Check::success()],
+        [Check::success()],
+        [Check::account(&account).data(&expected_data).build()],
+    ];
+    mollusk.process_and_validate_instruction_chain(
+        ixs.iter()
+            .zip(checks.iter().map(|c| c.as_ref()))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (account, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+}
+
+#[test]
+fn initialize_aligned_keeps_alignment_with_extensions() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let padding = RecordDataV2::padding_for_alignment(8).unwrap();
+    let account_length = RecordDataV2::LEN + padding + 8;
+    let expiry_growth = extension::ExtensionType::Expiry
+        .tlv_len()
+        .saturating_sub(padding)
+        .next_multiple_of(8);
+    let ixs = [
+        system_instruction::create_account(
+            &payer,
+            &account,
+            Rent::default().minimum_balance(account_length + expiry_growth),
+            account_length as u64,
+            &id(),
+        ),
+        instruction::initialize_aligned(&account, &authority, RecordMode::Standard, 8),
+        instruction::write(&account, &authority, 0, &7u64.to_le_bytes()),
+        instruction::set_expiry(&account, &authority, 100),
+    ];
+    let result = process_chain(
+        &mollusk,
+        &ixs,
+        &[Check::success()],
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (account, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+    let account_data = &result.get_account(&account).unwrap().data;
+    assert_eq!(
+        RecordData::writable_start_index(account_data).unwrap(),
+        RecordDataV2::LEN + padding + expiry_growth
+    );
+
+    // back the account data with u64s so it is 8-byte aligned, like account
+    // data passed to programs
+    let mut buffer = vec![0u64; account_data.len().div_ceil(8)];
+    let aligned_data = &mut bytemuck::cast_slice_mut::<u64, u8>(&mut buffer)[..account_data.len()];
+    aligned_data.copy_from_slice(account_data);
+    let record = TypedRecord::<u64>::unpack(aligned_data).unwrap();
+    assert_eq!(record.get(), Ok(&7));
+}
+
+#[test]
+fn initialize_aligned_fail_invalid_alignment() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let account_length = RecordDataV2::LEN + 16;
+    mollusk.process_and_validate_instruction(
+        &instruction::initialize_aligned(&account, &authority, RecordMode::Standard, 12),
+        &[
+            (authority, Account::default()),
+            (
+                account,
+                Account {
+                    lamports: Rent::default().minimum_balance(account_length),
+                    data: vec![0; account_length],
+                    owner: id(),
+                    ..Default::default()
+                },
+            ),
+        ],
+        &[Check::err(ProgramError::InvalidArgument)],
+    );
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+struct Config {
+    admin: Address,
+    threshold: u64,
+    limit: u64,
+}
+
+#[test]
+fn typed_record_write_field_success() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let config = Config {
+        admin: Address::new_unique(),
+        threshold: 2,
+        limit: 10,
+    };
+    let mut ixs = initialize_instructions(
+        &payer,
+        &authority,
+        &account,
+        &[0u8; std::mem::size_of::<Config>()],
+    )
+    .to_vec();
+    ixs.push(instruction::write_typed(&account, &authority, &config));
+    ixs.push(write_field!(&account, &authority, Config, threshold, 3));
+    let checks = ixs.iter().map(|_| [Check::success()]).collect::<Vec<_>>();
+    let result = mollusk.process_and_validate_instruction_chain(
+        ixs.iter()
+            .zip(checks.iter().map(|c| c.as_ref()))
+            .collect::<Vec<_>>()
+            .as_slice(),
+        &[
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            (authority, Account::default()),
+            (account, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+    );
+    let account_data = &result.get_account(&account).unwrap().data;
+    let record = TypedRecord::<Config>::unpack(account_data).unwrap();
+    assert_eq!(record.header.authority(), &authority);
+    assert_eq!(
+        record.read(),
+        Config {
+            threshold: 3,
+            ..config
+        }
+    );
+}
+
+/// Instructions creating a standard mode record holding 8 bytes, funded for
+/// its extension region to later grow to hold `extension_types`, along with
+/// the accounts they need
+///
+/// Sponsored records are initialized with the payer as their rent
+/// beneficiary.
+fn extended_record_setup(
+    payer: &Address,
+    authority: &Address,
+    account: &Address,
+    sponsored: bool,
+    extension_types: &[extension::ExtensionType],
+) -> (Vec<Instruction>, Vec<(Address, Account)>) {
+    let (initialize, initial_extensions) = if sponsored {
+        (
+            instruction::initialize_sponsored(account, authority, payer, RecordMode::Standard),
+            extension::ExtensionType::RentBeneficiary.tlv_len(),
+        )
+    } else {
+        (
+            instruction::initialize_with_mode(account, authority, RecordMode::Standard),
+            0,
+        )
+    };
+    let account_length = RecordDataV2::LEN
+        .checked_add(initial_extensions)
+        .and_then(|length| length.checked_add(8))
+        .unwrap();
+    let funded_length = account_length
+        .checked_add(
+            extension_types
+                .iter()
+                .map(|extension_type| extension_type.tlv_len())
+                .sum(),
+        )
+        .unwrap();
+    let ixs = vec![
+        system_instruction::create_account(
+            payer,
+            account,
+            Rent::default().minimum_balance(funded_length),
+            account_length as u64,
+            &id(),
+        ),
+        initialize,
+    ];
+    let accounts = vec![
+        (
+            *payer,
+            Account {
+                lamports: 1_000_000_000,
+                ..Default::default()
+            },
+        ),
+        (*authority, Account::default()),
+        (*account, Account::default()),
+        mollusk_svm::program::keyed_account_for_system_program(),
+    ];
+    (ixs, accounts)
+}
+
+/// Account data of a standard mode record holding the given extensions, as
+/// their type and value, followed by `payload`
+fn extended_record_data(
+    authority: &Address,
+    extensions: &[(extension::ExtensionType, &[u8])],
+    payload: &[u8],
+) -> Vec<u8> {
+    let mut tlv_data = vec![];
+    for (extension_type, value) in extensions {
+        tlv_data.extend_from_slice(&(*extension_type as u16).to_le_bytes());
+        tlv_data.extend_from_slice(&(value.len() as u16).to_le_bytes());
+        tlv_data.extend_from_slice(value);
+    }
+    let mut account_data = bytemuck::bytes_of(&RecordDataV2 {
+        version: RecordDataV2::VERSION,
+        authority: *authority,
+        mode: RecordMode::Standard as u8,
+        high_water_mark: [0; 8],
+        extensions_length: (tlv_data.len() as u32).to_le_bytes(),
+        alignment: 1,
+    })
+    .to_vec();
+    account_data.extend_from_slice(&tlv_data);
+    account_data.extend_from_slice(payload);
+    account_data
+}
+
+/// Processes a chain of instructions which must all succeed, except for the
+/// last one, validated with `last_checks`
+fn process_chain(
+    mollusk: &Mollusk,
+    ixs: &[Instruction],
+    last_checks: &[Check],
+    accounts: &[(Address, Account)],
+) -> InstructionResult {
+    let success = [Check::success()];
+    let mut chain = ixs
+        .iter()
+        .map(|ix| (ix, success.as_slice()))
+        .collect::<Vec<_>>();
+    if let Some((_, checks)) = chain.last_mut() {
+        *checks = last_checks;
+    }
+    mollusk.process_and_validate_instruction_chain(&chain, accounts)
+}
+
+#[test]
+fn sponsored_record_close_success() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let sponsor = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let (ixs, accounts) = extended_record_setup(&sponsor, &authority, &account, true, &[]);
+    let expected_data = extended_record_data(
+        &authority,
+        &[(extension::ExtensionType::RentBeneficiary, sponsor.as_ref())],
+        &[0; 8],
+    );
+    let result = process_chain(
+        &mollusk,
+        &ixs,
+        &[
+            Check::success(),
+            Check::account(&account).data(&expected_data).build(),
+        ],
+        &accounts,
+    );
+    mollusk.process_and_validate_instruction(
+        &instruction::close_account(&account, &authority, &sponsor),
+        &result.resulting_accounts,
+        &[
+            Check::success(),
+            Check::account(&sponsor).lamports(1_000_000_000).build(),
+        ],
+    );
+}
+
+#[test]
+fn sponsored_record_close_fail_wrong_recipient() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let sponsor = Address::new_unique();
+    let authority = Address::new_unique();
+    let recipient = Address::new_unique();
+    let account = Address::new_unique();
+    let (mut ixs, mut accounts) = extended_record_setup(&sponsor, &authority, &account, true, &[]);
+    ixs.push(instruction::close_account(&account, &authority, &recipient));
+    accounts.push((recipient, Account::default()));
+    process_chain(
+        &mollusk,
+        &ixs,
+        &[Check::err(ProgramError::Custom(
+            RecordError::IncorrectBeneficiary as u32,
+        ))],
+        &accounts,
+    );
+}
+
+#[test]
+fn reallocate_funded_success() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let sponsor = Address::new_unique();
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let (mut ixs, mut accounts) = extended_record_setup(&sponsor, &authority, &account, true, &[]);
+    ixs.push(instruction::with_rent_beneficiary(
+        instruction::reallocate_funded(&account, &authority, &payer, 64),
+        &sponsor,
+    ));
+    accounts.push((
+        payer,
+        Account {
+            lamports: 1_000_000_000,
+            ..Default::default()
+        },
+    ));
+    let expected_data = extended_record_data(
+        &authority,
+        &[(extension::ExtensionType::RentBeneficiary, payer.as_ref())],
+        &[0; 64],
+    );
+    let result = process_chain(
+        &mollusk,
+        &ixs,
+        &[
+            Check::success(),
+            Check::account(&account)
+                .data(&expected_data)
+                .lamports(Rent::default().minimum_balance(expected_data.len()))
+                .build(),
+        ],
+        &accounts,
+    );
+    mollusk.process_and_validate_instruction(
+        &instruction::close_account(&account, &authority, &payer),
+        &result.resulting_accounts,
+        &[Check::success()],
+    );
+}
+
+#[test]
+fn reallocate_funded_fail_displace_unsigned_beneficiary() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let sponsor = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let (mut ixs, accounts) = extended_record_setup(&sponsor, &authority, &account, true, &[]);
+    // a call needing no rent must not hand the sponsor's deposit to the payer
+    ixs.push(instruction::reallocate_funded(
+        &account, &authority, &authority, 8,
+    ));
+    process_chain(
+        &mollusk,
+        &ixs,
+        &[Check::err(ProgramError::NotEnoughAccountKeys)],
+        &accounts,
+    );
+
+    let mut ix = instruction::with_rent_beneficiary(
+        instruction::reallocate_funded(&account, &authority, &authority, 64),
+        &sponsor,
+    );
+    ix.accounts[4].is_signer = false;
+    ixs.pop();
+    ixs.push(ix);
+    process_chain(
+        &mollusk,
+        &ixs,
+        &[Check::err(ProgramError::MissingRequiredSignature)],
+        &accounts,
+    );
+}
+
+#[test]
+fn reallocate_funded_zero_growth_keeps_beneficiary() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let sponsor = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let (mut ixs, accounts) = extended_record_setup(&sponsor, &authority, &account, true, &[]);
+    ixs.push(instruction::with_rent_beneficiary(
+        instruction::reallocate_funded(&account, &authority, &authority, 8),
+        &sponsor,
+    ));
+    let expected_data = extended_record_data(
+        &authority,
+        &[(extension::ExtensionType::RentBeneficiary, sponsor.as_ref())],
+        &[0; 8],
+    );
+    let result = process_chain(
+        &mollusk,
+        &ixs,
+        &[
+            Check::success(),
+            Check::account(&account).data(&expected_data).build(),
+        ],
+        &accounts,
+    );
+    mollusk.process_and_validate_instruction(
+        &instruction::close_account(&account, &authority, &authority),
+        &result.resulting_accounts,
+        &[Check::err(ProgramError::Custom(
+            RecordError::IncorrectBeneficiary as u32,
+        ))],
+    );
+}
+
+#[test]
+fn reallocate_funded_fail_wrong_owner() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let authority = Address::new_unique();
+    let payer = Address::new_unique();
+    let account = Address::new_unique();
+    mollusk.process_and_validate_instruction(
+        &instruction::reallocate_funded(&account, &authority, &payer, 64),
+        &[
+            (
+                account,
+                Account {
+                    owner: Address::new_unique(),
+                    ..initialized_record_account(&authority, &[0u8; 8])
+                },
+            ),
+            (authority, Account::default()),
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+        &[Check::err(ProgramError::Custom(
+            RecordError::IncorrectOwner as u32,
+        ))],
+    );
+}
+
+#[test]
+fn reallocate_funded_fail_not_writable() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let authority = Address::new_unique();
+    let payer = Address::new_unique();
+    let account = Address::new_unique();
+    let mut reallocate = instruction::reallocate_funded(&account, &authority, &payer, 64);
+    reallocate.accounts[0].is_writable = false;
+    mollusk.process_and_validate_instruction(
+        &reallocate,
+        &[
+            (account, initialized_record_account(&authority, &[0u8; 8])),
+            (authority, Account::default()),
+            (
+                payer,
+                Account {
+                    lamports: 1_000_000_000,
+                    ..Default::default()
+                },
+            ),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+        &[Check::err(ProgramError::Custom(
+            RecordError::AccountNotWritable as u32,
+        ))],
+    );
+}
+
+#[test]
+fn reallocate_funded_fail_record_as_payer() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    mollusk.process_and_validate_instruction(
+        &instruction::reallocate_funded(&account, &authority, &account, 64),
+        &[
+            (account, initialized_record_account(&authority, &[0u8; 8])),
+            (authority, Account::default()),
+            mollusk_svm::program::keyed_account_for_system_program(),
+        ],
+        &[Check::err(ProgramError::Custom(
+            RecordError::DuplicateAccount as u32,
+        ))],
+    );
+}
+
+#[test]
+fn reallocate_funded_grows_extension_region() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let (mut ixs, accounts) = extended_record_setup(&payer, &authority, &account, false, &[]);
+    ixs.push(instruction::reallocate_funded(
+        &account, &authority, &payer, 64,
+    ));
+    let expected_data = extended_record_data(
+        &authority,
+        &[(extension::ExtensionType::RentBeneficiary, payer.as_ref())],
+        &[0; 64],
+    );
+    process_chain(
+        &mollusk,
+        &ixs,
+        &[
+            Check::success(),
+            Check::account(&account)
+                .data(&expected_data)
+                .lamports(Rent::default().minimum_balance(expected_data.len()))
+                .build(),
+        ],
+        &accounts,
+    );
+}
+
+#[test]
+fn close_expired_success() {
+    let mut mollusk = Mollusk::new(&id(), "spl_record");
+    mollusk.warp_to_slot(200);
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let (mut ixs, accounts) = extended_record_setup(
+        &payer,
+        &authority,
+        &account,
+        false,
+        &[extension::ExtensionType::Expiry],
+    );
+    ixs.push(instruction::set_expiry(&account, &authority, 100));
+    let expected_data = extended_record_data(
+        &authority,
+        &[(extension::ExtensionType::Expiry, &100u64.to_le_bytes())],
+        &[0; 8],
+    );
+    let result = process_chain(
+        &mollusk,
+        &ixs,
+        &[
+            Check::success(),
+            Check::account(&account).data(&expected_data).build(),
+        ],
+        &accounts,
+    );
+    mollusk.process_and_validate_instruction(
+        &instruction::close_expired(&account, &authority),
+        &result.resulting_accounts,
+        &[
+            Check::success(),
+            Check::account(&account).lamports(0).build(),
+            Check::account(&authority)
+                .lamports(Rent::default().minimum_balance(expected_data.len()))
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn close_expired_fail_not_expired() {
+    let mut mollusk = Mollusk::new(&id(), "spl_record");
+    mollusk.warp_to_slot(99);
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let (mut ixs, accounts) = extended_record_setup(
+        &payer,
+        &authority,
+        &account,
+        false,
+        &[extension::ExtensionType::Expiry],
+    );
+    ixs.push(instruction::set_expiry(&account, &authority, 100));
+    ixs.push(instruction::close_expired(&account, &authority));
+    process_chain(
+        &mollusk,
+        &ixs,
+        &[Check::err(ProgramError::Custom(
+            RecordError::RecordNotExpired as u32,
+        ))],
+        &accounts,
+    );
+}
+
+#[test]
+fn close_expired_fail_without_expiry() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    mollusk.process_and_validate_instruction(
+        &instruction::close_expired(&account, &authority),
+        &[
+            (account, initialized_record_account(&authority, &[111u8; 8])),
+            (authority, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            RecordError::ExtensionNotFound as u32,
+        ))],
+    );
+}
+
+#[test]
+fn close_expired_fail_not_beneficiary() {
+    let mut mollusk = Mollusk::new(&id(), "spl_record");
+    mollusk.warp_to_slot(200);
+    let sponsor = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let (mut ixs, accounts) = extended_record_setup(
+        &sponsor,
+        &authority,
+        &account,
+        true,
+        &[extension::ExtensionType::Expiry],
+    );
+    ixs.push(instruction::set_expiry(&account, &authority, 100));
+    ixs.push(instruction::close_expired(&account, &authority));
+    process_chain(
+        &mollusk,
+        &ixs,
+        &[Check::err(ProgramError::Custom(
+            RecordError::IncorrectBeneficiary as u32,
+        ))],
+        &accounts,
+    );
+}
+
+#[test]
+fn close_expired_fail_token_gated_without_beneficiary() {
+    let mut mollusk = Mollusk::new(&id(), "spl_record");
+    mollusk.warp_to_slot(200);
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let mint = Address::new_unique();
+    let (mut ixs, mut accounts) = extended_record_setup(
+        &payer,
+        &authority,
+        &account,
+        false,
+        &[
+            extension::ExtensionType::Expiry,
+            extension::ExtensionType::TokenGate,
+        ],
+    );
+    ixs.push(instruction::set_expiry(&account, &authority, 100));
+    ixs.push(instruction::set_token_gated_authority(
+        &account, &authority, &mint,
+    ));
+    ixs.push(instruction::close_expired(&account, &mint));
+    accounts.push((mint, mint_account(&spl_generic_token::token::id())));
+    process_chain(
+        &mollusk,
+        &ixs,
+        &[Check::err(ProgramError::Custom(
+            RecordError::MissingRentBeneficiary as u32,
+        ))],
+        &accounts,
+    );
+}
+
+#[test]
+fn write_policy_rate_limit() {
+    let mut mollusk = Mollusk::new(&id(), "spl_record");
+    mollusk.warp_to_slot(10);
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let (mut ixs, accounts) = extended_record_setup(
+        &payer,
+        &authority,
+        &account,
+        false,
+        &[extension::ExtensionType::WritePolicy],
+    );
+    ixs.push(instruction::set_write_policy(
+        &account, &authority, 10, 100, 5,
+    ));
+    ixs.push(instruction::write(&account, &authority, 0, &[1; 8]));
+    let policy = extension::WritePolicy {
+        start_slot: 10u64.to_le_bytes(),
+        end_slot: 100u64.to_le_bytes(),
+        min_slot_gap: 5u64.to_le_bytes(),
+        next_write_slot: 15u64.to_le_bytes(),
+    };
+    let expected_data = extended_record_data(
+        &authority,
+        &[(
+            extension::ExtensionType::WritePolicy,
+            bytemuck::bytes_of(&policy),
+        )],
+        &[1; 8],
+    );
+    let result = process_chain(
+        &mollusk,
+        &ixs,
+        &[
+            Check::success(),
+            Check::account(&account).data(&expected_data).build(),
+        ],
+        &accounts,
+    );
+    mollusk.process_and_validate_instruction(
+        &instruction::write(&account, &authority, 0, &[2; 8]),
+        &result.resulting_accounts,
+        &[Check::err(ProgramError::Custom(
+            RecordError::WriteRateLimited as u32,
+        ))],
+    );
+}
+
+#[test]
+fn write_policy_fail_before_window() {
+    let mut mollusk = Mollusk::new(&id(), "spl_record");
+    mollusk.warp_to_slot(9);
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let (mut ixs, accounts) = extended_record_setup(
+        &payer,
+        &authority,
+        &account,
+        false,
+        &[extension::ExtensionType::WritePolicy],
+    );
+    ixs.push(instruction::set_write_policy(
+        &account, &authority, 10, 100, 0,
+    ));
+    ixs.push(instruction::write(&account, &authority, 0, &[1; 8]));
+    process_chain(
+        &mollusk,
+        &ixs,
+        &[Check::err(ProgramError::Custom(
+            RecordError::WriteOutsideWindow as u32,
+        ))],
+        &accounts,
+    );
+}
+
+#[test]
+fn write_policy_fail_after_window() {
+    let mut mollusk = Mollusk::new(&id(), "spl_record");
+    mollusk.warp_to_slot(101);
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let (mut ixs, accounts) = extended_record_setup(
+        &payer,
+        &authority,
+        &account,
+        false,
+        &[extension::ExtensionType::WritePolicy],
+    );
+    ixs.push(instruction::set_write_policy(
+        &account, &authority, 10, 100, 0,
+    ));
+    ixs.push(instruction::write_multiple(
+        &[account],
+        &authority,
+        vec![instruction::RecordWrite {
+            record_index: 0,
+            offset: 0,
+            data: &[1; 8],
+        }],
+    ));
+    process_chain(
+        &mollusk,
+        &ixs,
+        &[Check::err(ProgramError::Custom(
+            RecordError::WriteOutsideWindow as u32,
+        ))],
+        &accounts,
+    );
+}
+
+#[test]
+fn write_policy_counts_write_multiple_once() {
+    let mut mollusk = Mollusk::new(&id(), "spl_record");
+    mollusk.warp_to_slot(9);
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let limited_account = Address::new_unique();
+    let (mut ixs, mut accounts) = extended_record_setup(
+        &payer,
+        &authority,
+        &limited_account,
+        false,
+        &[extension::ExtensionType::WritePolicy],
+    );
+    ixs.push(instruction::set_write_policy(
+        &limited_account,
+        &authority,
+        9,
+        100,
+        5,
+    ));
+    ixs.extend(initialize_instructions(
+        &payer, &authority, &account, &[0u8; 8],
+    ));
+    // the limited record is written twice, counting as a single write
+    ixs.push(instruction::write_multiple(
+        &[account, limited_account],
+        &authority,
+        vec![
+            instruction::RecordWrite {
+                record_index: 0,
+                offset: 0,
+                data: &[1; 8],
+            },
+            instruction::RecordWrite {
+                record_index: 1,
+                offset: 0,
+                data: &[2; 4],
+            },
+            instruction::RecordWrite {
+                record_index: 1,
+                offset: 4,
+                data: &[3; 4],
+            },
+        ],
+    ));
+    accounts.push((account, Account::default()));
+    let policy = extension::WritePolicy {
+        start_slot: 9u64.to_le_bytes(),
+        end_slot: 100u64.to_le_bytes(),
+        min_slot_gap: 5u64.to_le_bytes(),
+        next_write_slot: 14u64.to_le_bytes(),
+    };
+    let expected_data = extended_record_data(
+        &authority,
+        &[(
+            extension::ExtensionType::WritePolicy,
+            bytemuck::bytes_of(&policy),
+        )],
+        &[2, 2, 2, 2, 3, 3, 3, 3],
+    );
+    process_chain(
+        &mollusk,
+        &ixs,
+        &[
+            Check::success(),
+            Check::account(&limited_account)
+                .data(&expected_data)
+                .build(),
+        ],
+        &accounts,
+    );
+}
+
+#[test]
+fn set_write_policy_fail_empty_window() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    mollusk.process_and_validate_instruction(
+        &instruction::set_write_policy(&account, &authority, 100, 10, 0),
+        &[
+            (account, initialized_record_account(&authority, &[111u8; 8])),
+            (authority, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            RecordError::InvalidWriteWindow as u32,
+        ))],
+    );
+}
+
+#[test]
+fn session_key_write_success() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let session_key = Address::new_unique();
+    let (mut ixs, mut accounts) = extended_record_setup(
+        &payer,
+        &authority,
+        &account,
+        false,
+        &[extension::ExtensionType::SessionKeys],
+    );
+    ixs.push(instruction::register_session_key(
+        &account,
+        &authority,
+        &session_key,
+        100,
+        Some(8),
+    ));
+    ixs.push(instruction::write(&account, &session_key, 0, &[1; 8]));
+    accounts.push((session_key, Account::default()));
+    let result = process_chain(&mollusk, &ixs, &[Check::success()], &accounts);
+    let account_data = &result.get_account(&account).unwrap().data;
+    assert_eq!(&account_data[account_data.len() - 8..], &[1; 8]);
+    let record = RecordAccount::unpack(account_data.as_slice()).unwrap();
+    let sessions = extension::get_extension::<extension::SessionKeys>(record.extensions()).unwrap();
+    assert_eq!(
+        sessions.keys[0],
+        extension::SessionKey {
+            key: session_key,
+            expiry_slot: 100u64.to_le_bytes(),
+            remaining_bytes: 0u64.to_le_bytes(),
+            holder: Address::default(),
+        }
+    );
+}
+
+#[test]
+fn session_key_write_fail_budget_exceeded() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let session_key = Address::new_unique();
+    let (mut ixs, mut accounts) = extended_record_setup(
+        &payer,
+        &authority,
+        &account,
+        false,
+        &[extension::ExtensionType::SessionKeys],
+    );
+    ixs.push(instruction::register_session_key(
+        &account,
+        &authority,
+        &session_key,
+        100,
+        Some(4),
+    ));
+    ixs.push(instruction::write(&account, &session_key, 0, &[1; 4]));
+    ixs.push(instruction::write(&account, &session_key, 4, &[1; 1]));
+    accounts.push((session_key, Account::default()));
+    process_chain(
+        &mollusk,
+        &ixs,
+        &[Check::err(ProgramError::Custom(
+            RecordError::SessionBudgetExceeded as u32,
+        ))],
+        &accounts,
+    );
+}
+
+#[test]
+fn session_key_write_fail_expired() {
+    let mut mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let session_key = Address::new_unique();
+    let (mut ixs, mut accounts) = extended_record_setup(
+        &payer,
+        &authority,
+        &account,
+        false,
+        &[extension::ExtensionType::SessionKeys],
+    );
+    ixs.push(instruction::register_session_key(
+        &account,
+        &authority,
+        &session_key,
+        100,
+        None,
+    ));
+    accounts.push((session_key, Account::default()));
+    let result = process_chain(&mollusk, &ixs, &[Check::success()], &accounts);
+
+    mollusk.warp_to_slot(100);
+    mollusk.process_and_validate_instruction(
+        &instruction::write(&account, &session_key, 0, &[1; 8]),
+        &result.resulting_accounts,
+        &[Check::err(ProgramError::Custom(
+            RecordError::SessionKeyExpired as u32,
+        ))],
+    );
+}
+
+#[test]
+fn session_key_write_fail_revoked() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let session_key = Address::new_unique();
+    let (mut ixs, mut accounts) = extended_record_setup(
+        &payer,
+        &authority,
+        &account,
+        false,
+        &[extension::ExtensionType::SessionKeys],
+    );
+    ixs.push(instruction::register_session_key(
+        &account,
+        &authority,
+        &session_key,
+        100,
+        None,
+    ));
+    ixs.push(instruction::revoke_session_key(
+        &account,
+        &authority,
+        &session_key,
+    ));
+    ixs.push(instruction::write(&account, &session_key, 0, &[1; 8]));
+    accounts.push((session_key, Account::default()));
+    process_chain(
+        &mollusk,
+        &ixs,
+        &[Check::err(ProgramError::Custom(
+            RecordError::IncorrectAuthority as u32,
+        ))],
+        &accounts,
+    );
+}
+
+#[test]
+fn session_key_write_fail_after_authority_change() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let new_authority = Address::new_unique();
+    let account = Address::new_unique();
+    let session_key = Address::new_unique();
+    let (mut ixs, mut accounts) = extended_record_setup(
+        &payer,
+        &authority,
+        &account,
+        false,
+        &[extension::ExtensionType::SessionKeys],
+    );
+    ixs.push(instruction::register_session_key(
+        &account,
+        &authority,
+        &session_key,
+        100,
+        None,
+    ));
+    accounts.extend([
+        (session_key, Account::default()),
+        (new_authority, Account::default()),
+    ]);
+    let result = process_chain(&mollusk, &ixs, &[Check::success()], &accounts);
+
+    for set_authority in [
+        instruction::set_authority(&account, &authority, &new_authority),
+        instruction::set_authority_checked(&account, &authority, &new_authority),
+    ] {
+        let result = mollusk.process_and_validate_instruction_chain(
+            &[
+                (&set_authority, &[Check::success()]),
+                (
+                    &instruction::write(&account, &session_key, 0, &[1; 8]),
+                    &[Check::err(ProgramError::Custom(
+                        RecordError::IncorrectAuthority as u32,
+                    ))],
+                ),
+            ],
+            &result.resulting_accounts,
+        );
+        let account_data = &result.get_account(&account).unwrap().data;
+        let record = RecordAccount::unpack(account_data.as_slice()).unwrap();
+        let sessions =
+            extension::get_extension::<extension::SessionKeys>(record.extensions()).unwrap();
+        assert_eq!(*sessions, extension::SessionKeys::default());
+    }
+}
+
+#[test]
+fn revoke_session_key_fail_not_registered() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let session_key = Address::new_unique();
+    mollusk.process_and_validate_instruction(
+        &instruction::revoke_session_key(&account, &authority, &session_key),
+        &[
+            (account, initialized_record_account(&authority, &[111u8; 8])),
+            (authority, Account::default()),
+            (session_key, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            RecordError::SessionKeyNotFound as u32,
+        ))],
+    );
+}
+
+fn mint_account(token_program: &Address) -> Account {
+    let mut data = vec![0; 82];
+    // is_initialized
+    data[45] = 1;
+    Account {
+        lamports: Rent::default().minimum_balance(data.len()),
+        data,
+        owner: *token_program,
+        ..Default::default()
+    }
+}
+
+fn token_account(token_program: &Address, mint: &Address, owner: &Address, amount: u64) -> Account {
+    let mut data = vec![0; 165];
+    data[..32].copy_from_slice(mint.as_ref());
+    data[32..64].copy_from_slice(owner.as_ref());
+    data[64..72].copy_from_slice(&amount.to_le_bytes());
+    // initialized account state
+    data[108] = 1;
+    if *token_program == spl_generic_token::token_2022::id() {
+        // account type, followed by empty extension data
+        data.extend_from_slice(&[2, 0, 0, 0, 0]);
+    }
+    Account {
+        lamports: Rent::default().minimum_balance(data.len()),
+        data,
+        owner: *token_program,
+        ..Default::default()
+    }
+}
+
+fn process_token_gated_write(
+    token_program: &Address,
+    token_amount: u64,
+    token_owner: Option<Address>,
+    expected_check: Check,
+) {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let mint = Address::new_unique();
+    let holder = Address::new_unique();
+    let holder_token_account = Address::new_unique();
+    let (mut ixs, mut accounts) = extended_record_setup(
+        &payer,
+        &authority,
+        &account,
+        false,
+        &[extension::ExtensionType::TokenGate],
+    );
+    ixs.push(instruction::set_token_gated_authority(
+        &account, &authority, &mint,
+    ));
+    ixs.push(instruction::with_gating_token_account(
+        instruction::write(&account, &holder, 0, &[1; 8]),
+        &holder_token_account,
+    ));
+    accounts.extend([
+        (mint, mint_account(token_program)),
+        (holder, Account::default()),
+        (
+            holder_token_account,
+            token_account(
+                token_program,
+                &mint,
+                &token_owner.unwrap_or(holder),
+                token_amount,
+            ),
+        ),
+    ]);
+    process_chain(&mollusk, &ixs, &[expected_check], &accounts);
+}
+
+#[test]
+fn token_gated_write_success() {
+    process_token_gated_write(&spl_generic_token::token::id(), 1, None, Check::success());
+}
+
+#[test]
+fn token_gated_write_token_2022_success() {
+    process_token_gated_write(
+        &spl_generic_token::token_2022::id(),
+        1,
+        None,
+        Check::success(),
+    );
+}
+
+#[test]
+fn token_gated_write_fail_empty_token_account() {
+    process_token_gated_write(
+        &spl_generic_token::token::id(),
+        0,
+        None,
+        Check::err(ProgramError::Custom(RecordError::TokenNotHeld as u32)),
+    );
+}
+
+#[test]
+fn token_gated_write_fail_not_token_owner() {
+    process_token_gated_write(
+        &spl_generic_token::token_2022::id(),
+        1,
+        Some(Address::new_unique()),
+        Check::err(ProgramError::Custom(RecordError::TokenNotHeld as u32)),
+    );
+}
+
+#[test]
+fn token_gated_write_multiple() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let other_account = Address::new_unique();
+    let mint = Address::new_unique();
+    let holder = Address::new_unique();
+    let holder_token_account = Address::new_unique();
+    let token_program = spl_generic_token::token::id();
+    let (mut ixs, mut accounts) = extended_record_setup(
+        &payer,
+        &authority,
+        &account,
+        false,
+        &[extension::ExtensionType::TokenGate],
+    );
+    let (other_ixs, _) = extended_record_setup(
+        &payer,
+        &authority,
+        &other_account,
+        false,
+        &[extension::ExtensionType::TokenGate],
+    );
+    ixs.extend(other_ixs);
+    for record in [account, other_account] {
+        ixs.push(instruction::set_token_gated_authority(
+            &record, &authority, &mint,
+        ));
+    }
+    accounts.extend([
+        (other_account, Account::default()),
+        (mint, mint_account(&token_program)),
+        (holder, Account::default()),
+        (
+            holder_token_account,
+            token_account(&token_program, &mint, &holder, 1),
+        ),
+    ]);
+    let result = process_chain(&mollusk, &ixs, &[Check::success()], &accounts);
+
+    // records gated by the same mint share one token account
+    let write_multiple = |signer: &Address| {
+        instruction::with_gating_token_account(
+            instruction::write_multiple(
+                &[account, other_account],
+                signer,
+                vec![
+                    instruction::RecordWrite {
+                        record_index: 0,
+                        offset: 0,
+                        data: &[1; 8],
+                    },
+                    instruction::RecordWrite {
+                        record_index: 1,
+                        offset: 0,
+                        data: &[2; 8],
+                    },
+                ],
+            ),
+            &holder_token_account,
+        )
+    };
+    let with_payload = |record: &Address, payload: [u8; 8]| {
+        let mut data = result.get_account(record).unwrap().data.clone();
+        let payload_start = data.len() - 8;
+        data[payload_start..].copy_from_slice(&payload);
+        data
+    };
+    let expected_data = [
+        with_payload(&account, [1; 8]),
+        with_payload(&other_account, [2; 8]),
+    ];
+    mollusk.process_and_validate_instruction(
+        &write_multiple(&holder),
+        &result.resulting_accounts,
+        &[
+            Check::success(),
+            Check::account(&account).data(&expected_data[0]).build(),
+            Check::account(&other_account)
+                .data(&expected_data[1])
+                .build(),
+        ],
+    );
+    mollusk.process_and_validate_instruction(
+        &write_multiple(&mint),
+        &result.resulting_accounts,
+        &[Check::err(ProgramError::Custom(
+            RecordError::TokenNotHeld as u32,
+        ))],
+    );
+}
+
+#[test]
+fn token_gated_set_authority_removes_gate() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let mint = Address::new_unique();
+    let holder = Address::new_unique();
+    let holder_token_account = Address::new_unique();
+    let new_authority = Address::new_unique();
+    let token_program = spl_generic_token::token::id();
+    let (mut ixs, mut accounts) = extended_record_setup(
+        &payer,
+        &authority,
+        &account,
+        false,
+        &[extension::ExtensionType::TokenGate],
+    );
+    ixs.push(instruction::set_token_gated_authority(
+        &account, &authority, &mint,
+    ));
+    ixs.push(instruction::with_gating_token_account(
+        instruction::set_authority(&account, &holder, &new_authority),
+        &holder_token_account,
+    ));
+    ixs.push(instruction::write(&account, &new_authority, 0, &[1; 8]));
+    accounts.extend([
+        (mint, mint_account(&token_program)),
+        (holder, Account::default()),
+        (
+            holder_token_account,
+            token_account(&token_program, &mint, &holder, 1),
+        ),
+        (new_authority, Account::default()),
+    ]);
+    let result = process_chain(&mollusk, &ixs, &[Check::success()], &accounts);
+    let account_data = &result.get_account(&account).unwrap().data;
+    let record = RecordAccount::unpack(account_data.as_slice()).unwrap();
+    let gate = extension::get_extension::<extension::TokenGate>(record.extensions()).unwrap();
+    assert!(!gate.is_enabled());
+}
+
+#[test]
+fn token_gated_session_key_lapses_with_token() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let mint = Address::new_unique();
+    let holder = Address::new_unique();
+    let holder_token_account = Address::new_unique();
+    let session_key = Address::new_unique();
+    let token_program = spl_generic_token::token::id();
+    let (mut ixs, mut accounts) = extended_record_setup(
+        &payer,
+        &authority,
+        &account,
+        false,
+        &[
+            extension::ExtensionType::TokenGate,
+            extension::ExtensionType::SessionKeys,
+        ],
+    );
+    ixs.push(instruction::set_token_gated_authority(
+        &account, &authority, &mint,
+    ));
+    ixs.push(instruction::with_gating_token_account(
+        instruction::register_session_key(&account, &holder, &session_key, 100, None),
+        &holder_token_account,
+    ));
+    accounts.extend([
+        (mint, mint_account(&token_program)),
+        (holder, Account::default()),
+        (
+            holder_token_account,
+            token_account(&token_program, &mint, &holder, 1),
+        ),
+        (session_key, Account::default()),
+    ]);
+    let result = process_chain(&mollusk, &ixs, &[Check::success()], &accounts);
+    let account_data = &result.get_account(&account).unwrap().data;
+    let record = RecordAccount::unpack(account_data.as_slice()).unwrap();
+    let sessions = extension::get_extension::<extension::SessionKeys>(record.extensions()).unwrap();
+    assert_eq!(sessions.keys[0].holder, holder);
+
+    let write = instruction::with_gating_token_account(
+        instruction::write(&account, &session_key, 0, &[1; 8]),
+        &holder_token_account,
+    );
+    mollusk.process_and_validate_instruction(
+        &write,
+        &result.resulting_accounts,
+        &[Check::success()],
+    );
+
+    // the token moves to a new owner, taking the session key's rights with it
+    let mut transferred_accounts = result.resulting_accounts.clone();
+    for (address, token_account_state) in transferred_accounts.iter_mut() {
+        if *address == holder_token_account {
+            *token_account_state = token_account(&token_program, &mint, &holder, 0);
+        }
+    }
+    mollusk.process_and_validate_instruction(
+        &write,
+        &transferred_accounts,
+        &[Check::err(ProgramError::Custom(
+            RecordError::TokenNotHeld as u32,
+        ))],
+    );
+    mollusk.process_and_validate_instruction(
+        &instruction::write(&account, &session_key, 0, &[1; 8]),
+        &result.resulting_accounts,
+        &[Check::err(ProgramError::NotEnoughAccountKeys)],
+    );
+}
+
+#[test]
+fn set_token_gated_authority_fail_not_mint() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let mint = Address::new_unique();
+    let (mut ixs, mut accounts) = extended_record_setup(
+        &payer,
+        &authority,
+        &account,
+        false,
+        &[extension::ExtensionType::TokenGate],
+    );
+    ixs.push(instruction::set_token_gated_authority(
+        &account, &authority, &mint,
+    ));
+    accounts.push((mint, Account::default()));
+    process_chain(
+        &mollusk,
+        &ixs,
+        &[Check::err(ProgramError::IncorrectProgramId)],
+        &accounts,
+    );
+}
+
+/// Ed25519 program instruction verifying the signature of `signing_key` over
+/// a signed write
+fn ed25519_instruction(
+    signing_key: &SigningKey,
+    account: &Address,
+    nonce: u64,
+    data: &[u8],
+) -> Instruction {
+    let message = instruction::write_signed_message(account, 0, nonce, data);
+    solana_ed25519_program::new_ed25519_instruction_with_signature(
+        &message,
+        &signing_key.sign(&message).to_bytes(),
+        &signing_key.verifying_key().to_bytes(),
+    )
+}
+
+#[test]
+fn write_signed_success() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let signing_key = SigningKey::from_bytes(&[1; 32]);
+    let payer = Address::new_unique();
+    let authority = Address::new_from_array(signing_key.verifying_key().to_bytes());
+    let account = Address::new_unique();
+    let data = [1u8; 8];
+    let (mut ixs, accounts) = extended_record_setup(
+        &payer,
+        &authority,
+        &account,
+        false,
+        &[extension::ExtensionType::WriteNonce],
+    );
+    ixs.push(ed25519_instruction(&signing_key, &account, 0, &data));
+    ixs.push(instruction::write_signed(&account, 0, 0, &data));
+    let expected_data = extended_record_data(
+        &authority,
+        &[(extension::ExtensionType::WriteNonce, &1u64.to_le_bytes())],
+        &data,
+    );
+    process_chain(
+        &mollusk,
+        &ixs,
+        &[
+            Check::success(),
+            Check::account(&account).data(&expected_data).build(),
+        ],
+        &accounts,
+    );
+}
+
+#[test]
+fn write_signed_rent_exact_record_with_payer() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let signing_key = SigningKey::from_bytes(&[1; 32]);
+    let payer = Address::new_unique();
+    let relayer = Address::new_unique();
+    let authority = Address::new_from_array(signing_key.verifying_key().to_bytes());
+    let account = Address::new_unique();
+    let data = [1u8; 8];
+    let (mut ixs, mut accounts) = extended_record_setup(&payer, &authority, &account, false, &[]);
+    accounts.push((
+        relayer,
+        Account {
+            lamports: 1_000_000_000,
+            ..Default::default()
+        },
+    ));
+    ixs.push(ed25519_instruction(&signing_key, &account, 0, &data));
+    ixs.push(instruction::write_signed(&account, 0, 0, &data));
+    process_chain(
+        &mollusk,
+        &ixs,
+        &[Check::err(ProgramError::NotEnoughAccountKeys)],
+        &accounts,
+    );
+
+    ixs.pop();
+    ixs.push(instruction::with_write_signed_payer(
+        instruction::write_signed(&account, 0, 0, &data),
+        &relayer,
+    ));
+    let expected_data = extended_record_data(
+        &authority,
+        &[(extension::ExtensionType::WriteNonce, &1u64.to_le_bytes())],
+        &data,
+    );
+    process_chain(
+        &mollusk,
+        &ixs,
+        &[
+            Check::success(),
+            Check::account(&account)
+                .data(&expected_data)
+                .lamports(Rent::default().minimum_balance(expected_data.len()))
+                .build(),
+        ],
+        &accounts,
+    );
+}
+
+#[test]
+fn write_signed_fail_replay() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let signing_key = SigningKey::from_bytes(&[1; 32]);
+    let payer = Address::new_unique();
+    let authority = Address::new_from_array(signing_key.verifying_key().to_bytes());
+    let account = Address::new_unique();
+    let data = [1u8; 8];
+    let (mut ixs, accounts) = extended_record_setup(
+        &payer,
+        &authority,
+        &account,
+        false,
+        &[extension::ExtensionType::WriteNonce],
+    );
+    for _ in 0..2 {
+        ixs.push(ed25519_instruction(&signing_key, &account, 0, &data));
+        ixs.push(instruction::write_signed(&account, 0, 0, &data));
+    }
+    process_chain(
+        &mollusk,
+        &ixs,
+        &[Check::err(ProgramError::Custom(
+            RecordError::NonceMismatch as u32,
+        ))],
+        &accounts,
+    );
+}
+
+#[test]
+fn write_signed_fail_wrong_signer() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let signing_key = SigningKey::from_bytes(&[1; 32]);
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let data = [1u8; 8];
+    let (mut ixs, accounts) = extended_record_setup(
+        &payer,
+        &authority,
+        &account,
+        false,
+        &[extension::ExtensionType::WriteNonce],
+    );
+    ixs.push(ed25519_instruction(&signing_key, &account, 0, &data));
+    ixs.push(instruction::write_signed(&account, 0, 0, &data));
+    process_chain(
+        &mollusk,
+        &ixs,
+        &[Check::err(ProgramError::Custom(
+            RecordError::IncorrectAuthority as u32,
+        ))],
+        &accounts,
+    );
+}
+
+#[test]
+fn write_signed_fail_message_mismatch() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let signing_key = SigningKey::from_bytes(&[1; 32]);
+    let payer = Address::new_unique();
+    let authority = Address::new_from_array(signing_key.verifying_key().to_bytes());
+    let account = Address::new_unique();
+    let (mut ixs, accounts) = extended_record_setup(
+        &payer,
+        &authority,
+        &account,
+        false,
+        &[extension::ExtensionType::WriteNonce],
+    );
+    ixs.push(ed25519_instruction(&signing_key, &account, 0, &[1; 8]));
+    ixs.push(instruction::write_signed(&account, 0, 0, &[2; 8]));
+    process_chain(
+        &mollusk,
+        &ixs,
+        &[Check::err(ProgramError::Custom(
+            RecordError::InvalidWriteSignature as u32,
+        ))],
+        &accounts,
+    );
+}
+
+fn process_token_gated_write_signed(signing_key: &SigningKey, expected_check: Check) {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let mint_key = SigningKey::from_bytes(&[2; 32]);
+    let mint = Address::new_from_array(mint_key.verifying_key().to_bytes());
+    let holder_key = SigningKey::from_bytes(&[1; 32]);
+    let holder = Address::new_from_array(holder_key.verifying_key().to_bytes());
+    let holder_token_account = Address::new_unique();
+    let token_program = spl_generic_token::token::id();
+    let data = [1u8; 8];
+    let (mut ixs, mut accounts) = extended_record_setup(
+        &payer,
+        &authority,
+        &account,
+        false,
+        &[
+            extension::ExtensionType::TokenGate,
+            extension::ExtensionType::WriteNonce,
+        ],
+    );
+    ixs.push(instruction::set_token_gated_authority(
+        &account, &authority, &mint,
+    ));
+    ixs.push(ed25519_instruction(signing_key, &account, 0, &data));
+    ixs.push(instruction::with_gating_token_account(
+        instruction::write_signed(&account, 0, 0, &data),
+        &holder_token_account,
+    ));
+    accounts.extend([
+        (mint, mint_account(&token_program)),
+        (
+            holder_token_account,
+            token_account(&token_program, &mint, &holder, 1),
+        ),
+    ]);
+    process_chain(&mollusk, &ixs, &[expected_check], &accounts);
+}
+
+#[test]
+fn write_signed_token_gated_success() {
+    process_token_gated_write_signed(&SigningKey::from_bytes(&[1; 32]), Check::success());
+}
+
+#[test]
+fn write_signed_token_gated_fail_signed_by_mint() {
+    process_token_gated_write_signed(
+        &SigningKey::from_bytes(&[2; 32]),
+        Check::err(ProgramError::Custom(RecordError::TokenNotHeld as u32)),
+    );
+}
+
+#[test]
+fn write_signed_fail_missing_signature_instruction() {
+    let mollusk = Mollusk::new(&id(), "spl_record");
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let account = Address::new_unique();
+    let (mut ixs, accounts) = extended_record_setup(
+        &payer,
+        &authority,
+        &account,
+        false,
+        &[extension::ExtensionType::WriteNonce],
+    );
+    ixs.push(instruction::write_signed(&account, 0, 0, &[1; 8]));
+    process_chain(
+        &mollusk,
+        &ixs,
+        &[Check::err(ProgramError::Custom(
+            RecordError::InvalidWriteSignature as u32,
+        ))],
+        &accounts,
+    );
+}